@@ -1,24 +1,42 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::time::Duration;
+
 use bevy_app::{App, PreUpdate};
 use bevy_camera::{Camera, RenderTarget, visibility::Visibility};
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
+    change_detection::{DetectChanges as _, DetectChangesMut as _},
     entity::Entity,
+    hierarchy::{ChildOf, Children},
     message::{Message, MessageReader, MessageWriter},
-    query::With,
+    query::{Changed, With, Without},
     resource::Resource,
     schedule::{IntoScheduleConfigs as _, common_conditions::on_message},
-    system::{Query, Res, ResMut},
+    system::{Commands, Local, Query, Res, ResMut, SystemParam, SystemState},
+    world::World,
 };
+use bevy_input::{ButtonInput, gamepad::Gamepad, keyboard::KeyCode, touch::Touches};
+use bevy_input_focus::{InputFocus, InputFocusVisible};
 use bevy_math::Vec2;
-use bevy_time::Time;
-use bevy_ui::{Interaction, UiStack};
+use bevy_time::{Real, Time, Virtual};
+use bevy_ui::{
+    BackgroundColor, BorderColor, ComputedNode, Display, Interaction, Node, ScrollPosition,
+    UiGlobalTransform, UiStack,
+};
 use bevy_window::{PrimaryWindow, Window, WindowRef};
 use tiny_bail::prelude::*;
 
 use crate::{
-    Tooltip, TooltipContent, TooltipSettings, TooltipSystems, placement::TargetPoint,
+    StructuredContent, Tooltip, TooltipActivation, TooltipClock, TooltipCondition, TooltipContent,
+    TooltipDefaults, TooltipDirection, TooltipDisabled, TooltipDismissal, TooltipGroup,
+    TooltipHotkey, TooltipInherit, TooltipPlacement, TooltipProvider, TooltipSettings,
+    TooltipSuppressor, TooltipSystems, TooltipTemplate, TooltipTheme, TooltipTransfer,
+    localization::resolve_localized_content,
+    placeholder::{PlaceholderValue, resolve_placeholders},
+    placement::TargetPoint,
     rich_text::RichText,
+    structured::StructuredContentRoot,
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -28,8 +46,13 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         PreUpdate,
         (
+            evaluate_tooltip_conditions,
             update_tooltip_context,
             hide_tooltip.run_if(on_message::<HideTooltip>),
+            despawn_template_instances,
+            resolve_lazy_content.run_if(on_message::<ShowTooltip>),
+            resolve_localized_content.run_if(on_message::<ShowTooltip>),
+            resolve_placeholders.run_if(on_message::<ShowTooltip>),
             show_tooltip.run_if(on_message::<ShowTooltip>),
         )
             .chain()
@@ -49,12 +72,91 @@ pub(crate) struct TooltipContext {
     pub(crate) state: TooltipState,
     /// The current or previous target entity being interacted with.
     pub(crate) target: Entity,
-    /// The remaining duration of the current activation delay or transfer timeout (in milliseconds).
-    timer: u16,
+    /// Whether the target entity was found via input focus instead of the cursor.
+    pub(crate) focused: bool,
+    /// The [`TooltipGroup`] entity of the current or previous target entity.
+    group: Option<Entity>,
+    /// The cooldown and `from_active` values of the current or previous [`TooltipGroup`].
+    group_cooldown: Option<(Duration, bool)>,
+    /// Whether the current activation hotkey has been toggled on for the current target entity.
+    hotkey_toggled: bool,
+    /// The remaining duration of the current activation delay or transfer timeout.
+    timer: Duration,
+    /// The elapsed duration since the current tooltip was activated.
+    active_timer: Duration,
+    /// The remaining idle duration before the dismissed tooltip will be re-armed.
+    rearm_timer: Option<Duration>,
     /// The current cursor position or activation point.
     pub(crate) cursor_pos: Vec2,
+    /// The cursor position when the activation delay timer was last reset.
+    rest_pos: Vec2,
     /// The current tooltip parameters.
     pub(crate) tooltip: Tooltip,
+    /// The entities whose [`TooltipCondition`] returned `false` this frame.
+    failed_conditions: Vec<Entity>,
+    /// Whether tooltips are suppressed by a [`TooltipSuppressor`] this frame.
+    suppressed: bool,
+    /// Whether the tooltip was activated or changed targets this frame.
+    activated: bool,
+    /// The elapsed duration since the tooltip content was last refreshed.
+    refresh_timer: Duration,
+    /// Whether the tooltip content should be refreshed this frame.
+    refresh: bool,
+    /// The spawned instances of templated main and companion content.
+    template_instances: [Option<TemplateInstance>; 2],
+    /// The resolved lazy main and companion content, with their providers.
+    lazy_content: [Option<(TooltipProvider, TooltipContent)>; 2],
+    /// The arguments of the localized main and companion content, to fill in with placeholders.
+    pub(crate) localized_args: [Vec<(Cow<'static, str>, PlaceholderValue)>; 2],
+    /// The structured main and companion content currently spawned in the primary containers, with
+    /// their layout directions.
+    shown_structured: [Option<(StructuredContent, TooltipDirection)>; 2],
+}
+
+impl TooltipContext {
+    /// The entity that displays the main tooltip content.
+    pub(crate) fn content_entity(&self, primary: &TooltipSettings) -> Entity {
+        self.entity(&self.tooltip.content, primary.container, MAIN)
+    }
+
+    /// The entity that displays the companion tooltip content, if any.
+    pub(crate) fn companion_entity(&self, primary: &TooltipSettings) -> Option<Entity> {
+        self.tooltip
+            .companion
+            .as_ref()
+            .map(|companion| self.entity(companion, primary.companion_container, COMPANION))
+    }
+
+    fn entity(&self, content: &TooltipContent, container: Entity, slot: usize) -> Entity {
+        match content {
+            TooltipContent::Primary(_)
+            | TooltipContent::Structured(_)
+            | TooltipContent::Localized(_) => container,
+            &TooltipContent::Custom(id) => id,
+            TooltipContent::Template(_) => self.template_instances[slot]
+                .as_ref()
+                .map_or(Entity::PLACEHOLDER, |instance| instance.entity),
+            TooltipContent::Lazy(_) => self.lazy_content[slot]
+                .as_ref()
+                .map_or(Entity::PLACEHOLDER, |(_, content)| {
+                    self.entity(content, container, slot)
+                }),
+        }
+    }
+}
+
+/// The index of the main content in [`TooltipContext::template_instances`].
+const MAIN: usize = 0;
+/// The index of the companion content in [`TooltipContext::template_instances`].
+const COMPANION: usize = 1;
+
+/// An entity spawned from a [`TooltipTemplate`] for a target entity.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+struct TemplateInstance {
+    template: TooltipTemplate,
+    target: Entity,
+    entity: Entity,
 }
 
 impl Default for TooltipContext {
@@ -62,9 +164,73 @@ impl Default for TooltipContext {
         Self {
             state: TooltipState::Inactive,
             target: Entity::PLACEHOLDER,
-            timer: 0,
+            focused: false,
+            group: None,
+            group_cooldown: None,
+            hotkey_toggled: false,
+            timer: Duration::ZERO,
+            active_timer: Duration::ZERO,
+            rearm_timer: None,
             cursor_pos: Vec2::ZERO,
+            rest_pos: Vec2::ZERO,
             tooltip: Tooltip::cursor(Entity::PLACEHOLDER),
+            failed_conditions: Vec::new(),
+            suppressed: false,
+            activated: false,
+            refresh_timer: Duration::ZERO,
+            refresh: false,
+            template_instances: [None, None],
+            lazy_content: [None, None],
+            localized_args: [Vec::new(), Vec::new()],
+            shown_structured: [None, None],
+        }
+    }
+}
+
+fn evaluate_tooltip_conditions(
+    world: &mut World,
+    state: &mut SystemState<(
+        Query<(), With<TooltipSuppressor>>,
+        Query<(Entity, &TooltipCondition, &Interaction)>,
+        Option<Res<InputFocus>>,
+    )>,
+) {
+    let (suppressor_query, condition_query, input_focus) = r!(state.get(world));
+    let suppressed = !suppressor_query.is_empty();
+    let focus = input_focus.as_deref().and_then(InputFocus::get);
+    let conditions = condition_query
+        .iter()
+        .filter(|&(entity, _, interaction)| {
+            !matches!(interaction, Interaction::None) || focus == Some(entity)
+        })
+        .map(|(entity, condition, _)| (entity, condition.0))
+        .collect::<Vec<_>>();
+    world.resource_mut::<TooltipContext>().suppressed = suppressed;
+
+    let mut failed = Vec::new();
+    for (entity, system) in conditions {
+        if !world.run_system_with(system, entity).unwrap_or(false) {
+            failed.push(entity);
+        }
+    }
+    world.resource_mut::<TooltipContext>().failed_conditions = failed;
+}
+
+impl TooltipContext {
+    /// Update the current tooltip parameters from the target entity's tooltip and group.
+    fn set_tooltip(
+        &mut self,
+        tooltip: &Tooltip,
+        inherited: InheritedFields,
+        group: Option<(Entity, &TooltipGroup)>,
+    ) {
+        self.tooltip = tooltip.clone();
+        inherited.apply(&mut self.tooltip);
+        self.tooltip.dismissal.on_distance *= self.tooltip.dismissal.on_distance;
+        self.group = group.map(|(id, _)| id);
+        self.group_cooldown = group.map(|(_, group)| (group.cooldown, group.from_active));
+        if let Some(warmup) = group.and_then(|(_, group)| group.warmup) {
+            self.tooltip.activation.delay = warmup;
         }
     }
 }
@@ -74,22 +240,30 @@ fn update_tooltip_context(
     mut hide_tooltip: MessageWriter<HideTooltip>,
     mut show_tooltip: MessageWriter<ShowTooltip>,
     primary: Res<TooltipSettings>,
-    time: Res<Time>,
+    time: TooltipTime,
     ui_stack: Res<UiStack>,
+    input_focus: Option<Res<InputFocus>>,
+    input_focus_visible: Option<Res<InputFocusVisible>>,
+    hotkey_input: HotkeyInput,
+    touches: Option<Res<Touches>>,
     primary_window_query: Query<Entity, With<PrimaryWindow>>,
     window_query: Query<&Window>,
     camera_query: Query<&RenderTarget, With<Camera>>,
-    interaction_query: Query<(&Tooltip, &Interaction)>,
+    interaction_query: Query<(&Tooltip, &Interaction), Without<TooltipDisabled>>,
+    node_query: Query<(&ComputedNode, &UiGlobalTransform)>,
+    ancestors: TooltipAncestors,
 ) {
     let old_active = matches!(ctx.state, TooltipState::Active);
     let old_target = ctx.target;
-    let old_entity = match ctx.tooltip.content {
-        TooltipContent::Primary(_) => primary.container,
-        TooltipContent::Custom(id) => id,
-    };
+    let old_entity = ctx.content_entity(&primary);
+    let old_companion_entity = ctx.companion_entity(&primary);
+
+    let delta = time.delta(primary.clock);
 
     // TODO: Reconsider whether this is the right way to detect cursor movement.
     // Detect cursor movement.
+    let mut physical_cursor_pos = None;
+    let mut window_focused = false;
     for camera_target in &camera_query {
         let RenderTarget::Window(window) = camera_target else {
             continue;
@@ -102,23 +276,55 @@ fn update_tooltip_context(
         let window = c!(window_query.get(window));
 
         cq!(window.focused);
-        let cursor_pos = cq!(window.cursor_position());
+        window_focused = true;
+        // Fall back to the first touch position, like `bevy_ui` does.
+        let cursor_pos = cq!(window.cursor_position().or_else(|| {
+            touches
+                .as_ref()
+                .and_then(|touches| touches.first_pressed_position())
+        }));
+        physical_cursor_pos = Some(cursor_pos * window.scale_factor());
 
-        // Reset activation delay on cursor move.
-        if ctx.cursor_pos != cursor_pos
-            && matches!(ctx.state, TooltipState::Delayed)
-            && ctx.tooltip.activation.reset_delay_on_cursor_move
-        {
-            ctx.timer = ctx.tooltip.activation.delay;
+        // Reset activation delay on cursor move and / or high cursor speed.
+        if !ctx.focused && matches!(ctx.state, TooltipState::Delayed) {
+            let activation = &ctx.tooltip.activation;
+            let tolerance = activation.cursor_move_tolerance;
+            let moved = ctx.rest_pos.distance_squared(cursor_pos) > tolerance * tolerance;
+            let delta = delta.as_secs_f32();
+            let fast = delta > 0.0
+                && ctx.cursor_pos.distance(cursor_pos) / delta > activation.max_cursor_speed;
+            // With both limits, the timer keeps counting down while either limit is respected.
+            let reset = match (
+                activation.reset_delay_on_cursor_move,
+                activation.max_cursor_speed.is_finite(),
+            ) {
+                (true, true) => moved && fast,
+                (true, false) => moved,
+                (false, true) => fast,
+                (false, false) => false,
+            };
+            if reset {
+                ctx.timer = activation.delay;
+                ctx.rest_pos = cursor_pos;
+            }
         }
 
         // Dismiss tooltip if cursor has left the activation radius.
         if matches!(ctx.state, TooltipState::Active)
+            && !ctx.focused
             && ctx.cursor_pos.distance_squared(cursor_pos) > ctx.tooltip.dismissal.on_distance
         {
             ctx.state = TooltipState::Dismissed;
         }
 
+        // Restart re-arm timer on cursor move.
+        if ctx.cursor_pos != cursor_pos
+            && !ctx.focused
+            && matches!(ctx.state, TooltipState::Dismissed)
+        {
+            ctx.rearm_timer = ctx.tooltip.dismissal.rearm_on_idle;
+        }
+
         // Update cursor position.
         if !matches!(ctx.state, TooltipState::Active)
             || matches!(
@@ -134,72 +340,212 @@ fn update_tooltip_context(
 
     // Tick timer for transfer timeout / activation delay.
     if matches!(ctx.state, TooltipState::Inactive | TooltipState::Delayed) {
-        ctx.timer = ctx.timer.saturating_sub(time.delta().as_millis() as u16);
-        if matches!(ctx.state, TooltipState::Delayed) && ctx.timer == 0 {
+        ctx.timer = ctx.timer.saturating_sub(delta);
+        if matches!(ctx.state, TooltipState::Delayed) && ctx.timer.is_zero() {
             ctx.state = TooltipState::Active;
         }
     }
 
+    // Tick timer for display timeout.
+    if matches!(ctx.state, TooltipState::Active) {
+        ctx.active_timer = ctx.active_timer.saturating_add(delta);
+        if ctx
+            .tooltip
+            .dismissal
+            .on_timeout
+            .is_some_and(|timeout| ctx.active_timer >= timeout)
+        {
+            ctx.state = TooltipState::Dismissed;
+        }
+    } else {
+        ctx.active_timer = Duration::ZERO;
+    }
+
+    // Tick timer for re-arming a dismissed tooltip.
+    if matches!(ctx.state, TooltipState::Dismissed) {
+        if let Some(timer) = ctx.rearm_timer {
+            let timer = timer.saturating_sub(delta);
+            ctx.rearm_timer = Some(timer);
+            if timer.is_zero() {
+                ctx.state = TooltipState::Delayed;
+                ctx.timer = ctx.tooltip.activation.delay;
+            }
+        }
+    } else {
+        ctx.rearm_timer = None;
+    }
+
     // Find the highest entity in the `UiStack` that has a tooltip and is being interacted with.
-    let mut found_target = false;
-    for &entity in ui_stack.uinodes.iter().rev() {
-        let (tooltip, interaction) = cq!(interaction_query.get(entity));
-        match interaction {
-            Interaction::Pressed if tooltip.dismissal.on_click => {
-                ctx.target = entity;
+    let mut target = None;
+    if !ctx.suppressed {
+        target = ui_stack.uinodes.iter().rev().find_map(|&entity| {
+            let (tooltip, &interaction) = interaction_query.get(entity).ok()?;
+            (!matches!(interaction, Interaction::None) && !ctx.failed_conditions.contains(&entity))
+                .then_some((entity, tooltip, interaction))
+        });
+    }
+
+    // Otherwise, fall back to the focused entity if it has a tooltip.
+    let mut focused = false;
+    if target.is_none()
+        && !ctx.suppressed
+        && let Some(entity) = input_focus.and_then(|input_focus| input_focus.get())
+        && input_focus_visible.is_some_and(|visible| visible.0)
+        && let Ok((tooltip, _)) = interaction_query.get(entity)
+        && !ctx.failed_conditions.contains(&entity)
+    {
+        target = Some((entity, tooltip, Interaction::Hovered));
+        focused = true;
+    }
+
+    let found_target = target.is_some();
+    if let Some((entity, tooltip, interaction)) = target {
+        let inherited = ancestors.resolve(entity, tooltip);
+        let group = ancestors.group(entity);
+        let group_warm = group.is_some_and(|(group, _)| {
+            ctx.group == Some(group)
+                && (matches!(ctx.state, TooltipState::Active)
+                    || (matches!(ctx.state, TooltipState::Inactive) && !ctx.timer.is_zero()))
+        });
+
+        if matches!(interaction, Interaction::Pressed)
+            && inherited.dismissal.on_click
+            && !inherited.activation.long_press
+        {
+            ctx.target = entity;
+            ctx.state = TooltipState::Dismissed;
+            ctx.tooltip.transfer = inherited.transfer;
+        } else if ctx.target == entity && !matches!(ctx.state, TooltipState::Inactive) {
+            // Still interacting with the same target entity.
+            ctx.set_tooltip(tooltip, inherited, group);
+        } else {
+            // Switch to the new target entity.
+            let delay = group
+                .and_then(|(_, group)| group.warmup)
+                .unwrap_or(inherited.activation.delay);
+            ctx.state = if delay.is_zero()
+                || group_warm
+                || (matches!(ctx.state, TooltipState::Inactive)
+                    && !ctx.timer.is_zero()
+                    && ctx.tooltip.transfer.layer >= inherited.transfer.layer
+                    && (matches!((ctx.tooltip.transfer.group, inherited.transfer.group), (Some(x), Some(y)) if x == y)
+                        || ctx.target == entity))
+            {
+                TooltipState::Active
+            } else {
+                TooltipState::Delayed
+            };
+            ctx.target = entity;
+            ctx.timer = delay;
+            ctx.set_tooltip(tooltip, inherited, group);
+            ctx.active_timer = Duration::ZERO;
+            ctx.hotkey_toggled = false;
+            ctx.rest_pos = ctx.cursor_pos;
+        }
+
+        // Long-press tooltips are only active while the target entity is pressed and hovered.
+        if inherited.activation.long_press && !focused {
+            if !matches!(interaction, Interaction::Pressed) {
+                ctx.state = TooltipState::Delayed;
+                ctx.timer = ctx.tooltip.activation.delay;
+            } else if let Some(cursor_pos) = physical_cursor_pos
+                && let Ok((computed, &gt)) = node_query.get(entity)
+                && !computed.contains_point(gt, cursor_pos)
+            {
                 ctx.state = TooltipState::Dismissed;
-                ctx.tooltip.transfer = tooltip.transfer;
-                found_target = true;
-                break;
             }
-            Interaction::None => continue,
-            _ => (),
-        };
+        }
 
-        // Still hovering the same target entity.
-        if ctx.target == entity && !matches!(ctx.state, TooltipState::Inactive) {
-            ctx.tooltip = tooltip.clone();
-            ctx.tooltip.dismissal.on_distance *= ctx.tooltip.dismissal.on_distance;
-            found_target = true;
-            break;
+        // Hotkey-gated tooltips are only active while the hotkey is held or toggled on.
+        if let Some(hotkey) = inherited.activation.hotkey {
+            if inherited.activation.hotkey_toggle && hotkey_input.just_pressed(hotkey) {
+                ctx.hotkey_toggled = !ctx.hotkey_toggled;
+            }
+            let hotkey_active = if inherited.activation.hotkey_toggle {
+                ctx.hotkey_toggled
+            } else {
+                hotkey_input.pressed(hotkey)
+            };
+            if !hotkey_active && !matches!(ctx.state, TooltipState::Dismissed) {
+                ctx.state = TooltipState::Delayed;
+                ctx.timer = ctx.tooltip.activation.delay;
+            }
         }
 
-        // Switch to the new target entity.
-        ctx.state = if tooltip.activation.delay == 0
-            || (matches!(ctx.state, TooltipState::Inactive)
-                && ctx.timer > 0
-                && ctx.tooltip.transfer.layer >= tooltip.transfer.layer
-                && (matches!((ctx.tooltip.transfer.group, tooltip.transfer.group), (Some(x), Some(y)) if x == y)
-                    || ctx.target == entity))
+        // Swap in the detailed content while its hotkey is held.
+        if let Some(details) = &tooltip.details
+            && hotkey_input.pressed(details.hotkey)
         {
-            TooltipState::Active
-        } else {
-            TooltipState::Delayed
-        };
-        ctx.target = entity;
-        ctx.timer = tooltip.activation.delay;
-        ctx.tooltip = tooltip.clone();
-        ctx.tooltip.dismissal.on_distance *= ctx.tooltip.dismissal.on_distance;
-        found_target = true;
-        break;
+            ctx.tooltip.content = details.content.clone();
+        }
+
+        // Dismiss tooltip on key press, scroll, or window blur.
+        let dismissal = &inherited.dismissal;
+        if matches!(ctx.state, TooltipState::Active | TooltipState::Delayed)
+            && (dismissal
+                .on_key_press
+                .is_some_and(|hotkey| hotkey_input.just_pressed(hotkey))
+                || (dismissal.on_any_key_press
+                    && hotkey_input.any_key_just_pressed(&[
+                        inherited.activation.hotkey,
+                        tooltip.details.as_ref().map(|details| details.hotkey),
+                    ]))
+                || (dismissal.on_scroll && ancestors.scrolled(entity))
+                || (dismissal.on_window_blur && !window_focused))
+        {
+            ctx.state = TooltipState::Dismissed;
+        }
+
+        // There is no cursor to place the tooltip at when activated by input focus.
+        ctx.focused = focused;
+        if focused {
+            ctx.tooltip.placement = ctx.tooltip.placement.without_cursor();
+        }
     }
 
     // There is no longer a target entity.
     if !found_target && !matches!(ctx.state, TooltipState::Inactive) {
-        ctx.timer =
-            if matches!(ctx.state, TooltipState::Active) || !ctx.tooltip.transfer.from_active {
-                ctx.tooltip.transfer.timeout
-            } else {
-                0
-            };
+        let (timeout, from_active) = match ctx.group_cooldown {
+            Some(cooldown) => cooldown,
+            None => (
+                ctx.tooltip.transfer.timeout,
+                ctx.tooltip.transfer.from_active,
+            ),
+        };
+        ctx.timer = if matches!(ctx.state, TooltipState::Active) || !from_active {
+            timeout
+        } else {
+            Duration::ZERO
+        };
         ctx.state = TooltipState::Inactive;
     }
 
+    // Don't transfer while tooltips are suppressed.
+    if ctx.suppressed {
+        ctx.timer = Duration::ZERO;
+    }
+
     // Update tooltip if it has a target, or was activated, dismissed, or changed targets.
     let new_active = matches!(ctx.state, TooltipState::Active);
+    ctx.activated = new_active && (!old_active || old_target != ctx.target);
+
+    // Tick timer for refreshing lazy and templated content.
+    ctx.refresh = false;
+    if ctx.activated || !new_active {
+        ctx.refresh_timer = Duration::ZERO;
+    } else if let Some(interval) = ctx.tooltip.refresh {
+        ctx.refresh_timer = ctx.refresh_timer.saturating_add(delta);
+        if ctx.refresh_timer >= interval {
+            ctx.refresh_timer = Duration::ZERO;
+            ctx.refresh = true;
+        }
+    }
     if old_active != new_active || old_target != ctx.target || found_target {
         if old_active {
             hide_tooltip.write(HideTooltip { entity: old_entity });
+            if let Some(entity) = old_companion_entity {
+                hide_tooltip.write(HideTooltip { entity });
+            }
         }
         if new_active {
             show_tooltip.write(ShowTooltip);
@@ -207,6 +553,145 @@ fn update_tooltip_context(
     }
 }
 
+/// A [`SystemParam`] for looking up the ancestors of a tooltip's target entity.
+#[derive(SystemParam)]
+struct TooltipAncestors<'w, 's> {
+    child_of_query: Query<'w, 's, &'static ChildOf>,
+    group_query: Query<'w, 's, &'static TooltipGroup>,
+    defaults_query: Query<'w, 's, &'static TooltipDefaults>,
+    scrolled_query: Query<'w, 's, (), Changed<ScrollPosition>>,
+}
+
+impl TooltipAncestors<'_, '_> {
+    /// Find the nearest [`TooltipGroup`] on the entity or one of its ancestors.
+    fn group(&self, entity: Entity) -> Option<(Entity, &TooltipGroup)> {
+        core::iter::once(entity)
+            .chain(self.child_of_query.iter_ancestors(entity))
+            .find_map(|id| Some((id, self.group_query.get(id).ok()?)))
+    }
+
+    /// Resolve the tooltip's inherited fields from the nearest [`TooltipDefaults`].
+    fn resolve(&self, entity: Entity, tooltip: &Tooltip) -> InheritedFields {
+        let mut fields = InheritedFields {
+            placement: tooltip.placement,
+            activation: tooltip.activation,
+            dismissal: tooltip.dismissal,
+            transfer: tooltip.transfer,
+        };
+        let mut inherit = tooltip.inherit;
+        for defaults in core::iter::once(entity)
+            .chain(self.child_of_query.iter_ancestors(entity))
+            .filter_map(|id| self.defaults_query.get(id).ok())
+        {
+            if inherit == TooltipInherit::NONE {
+                break;
+            }
+            if inherit.placement
+                && let Some(placement) = defaults.placement
+            {
+                fields.placement = placement;
+                inherit.placement = false;
+            }
+            if inherit.activation
+                && let Some(activation) = defaults.activation
+            {
+                fields.activation = activation;
+                inherit.activation = false;
+            }
+            if inherit.dismissal
+                && let Some(dismissal) = defaults.dismissal
+            {
+                fields.dismissal = dismissal;
+                inherit.dismissal = false;
+            }
+            if inherit.transfer
+                && let Some(transfer) = defaults.transfer
+            {
+                fields.transfer = transfer;
+                inherit.transfer = false;
+            }
+        }
+        fields
+    }
+
+    /// Check whether one of the entity's ancestors was scrolled this frame.
+    fn scrolled(&self, entity: Entity) -> bool {
+        self.child_of_query
+            .iter_ancestors(entity)
+            .any(|ancestor| self.scrolled_query.contains(ancestor))
+    }
+}
+
+/// The fields of a [`Tooltip`] that can be inherited from [`TooltipDefaults`], resolved without
+/// cloning the tooltip's content.
+#[derive(Copy, Clone)]
+struct InheritedFields {
+    placement: TooltipPlacement,
+    activation: TooltipActivation,
+    dismissal: TooltipDismissal,
+    transfer: TooltipTransfer,
+}
+
+impl InheritedFields {
+    /// Overwrite the tooltip's inheritable fields.
+    fn apply(self, tooltip: &mut Tooltip) {
+        tooltip.placement = self.placement;
+        tooltip.activation = self.activation;
+        tooltip.dismissal = self.dismissal;
+        tooltip.transfer = self.transfer;
+    }
+}
+
+/// A [`SystemParam`] for reading the [`Time`] of a [`TooltipClock`].
+#[derive(SystemParam)]
+struct TooltipTime<'w> {
+    real: Res<'w, Time<Real>>,
+    virt: Res<'w, Time<Virtual>>,
+}
+
+impl TooltipTime<'_> {
+    fn delta(&self, clock: TooltipClock) -> Duration {
+        match clock {
+            TooltipClock::Real => self.real.delta(),
+            TooltipClock::Virtual => self.virt.delta(),
+        }
+    }
+}
+
+/// A [`SystemParam`] for checking whether a [`TooltipHotkey`] is pressed.
+#[derive(SystemParam)]
+struct HotkeyInput<'w, 's> {
+    keyboard: Option<Res<'w, ButtonInput<KeyCode>>>,
+    gamepad_query: Query<'w, 's, &'static Gamepad>,
+}
+
+impl HotkeyInput<'_, '_> {
+    fn pressed(&self, hotkey: TooltipHotkey) -> bool {
+        match hotkey {
+            TooltipHotkey::Key(key) => self.keyboard.as_ref().is_some_and(|x| x.pressed(key)),
+            TooltipHotkey::GamepadButton(button) => {
+                self.gamepad_query.iter().any(|x| x.pressed(button))
+            }
+        }
+    }
+
+    fn any_key_just_pressed(&self, ignore: &[Option<TooltipHotkey>]) -> bool {
+        self.keyboard.as_ref().is_some_and(|x| {
+            x.get_just_pressed()
+                .any(|&key| !ignore.contains(&Some(TooltipHotkey::Key(key))))
+        })
+    }
+
+    fn just_pressed(&self, hotkey: TooltipHotkey) -> bool {
+        match hotkey {
+            TooltipHotkey::Key(key) => self.keyboard.as_ref().is_some_and(|x| x.just_pressed(key)),
+            TooltipHotkey::GamepadButton(button) => {
+                self.gamepad_query.iter().any(|x| x.just_pressed(button))
+            }
+        }
+    }
+}
+
 /// The current state of the tooltip system.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
@@ -237,26 +722,218 @@ fn hide_tooltip(
     }
 }
 
+/// Despawn the spawned instances of templated content once the tooltip is no longer active.
+fn despawn_template_instances(mut commands: Commands, mut ctx: ResMut<TooltipContext>) {
+    rq!(!matches!(ctx.state, TooltipState::Active)
+        && ctx.template_instances.iter().any(Option::is_some));
+    for instance in ctx.template_instances.iter_mut().filter_map(Option::take) {
+        commands.entity(instance.entity).try_despawn();
+    }
+}
+
+fn resolve_lazy_content(world: &mut World) {
+    let ctx = world.resource::<TooltipContext>();
+    let target = ctx.target;
+    let refresh = ctx.activated || ctx.refresh;
+    let provider = |content: &TooltipContent| match content {
+        &TooltipContent::Lazy(provider) => Some(provider),
+        _ => None,
+    };
+    let providers = [
+        (MAIN, provider(&ctx.tooltip.content)),
+        (COMPANION, ctx.tooltip.companion.as_ref().and_then(provider)),
+    ];
+
+    for (slot, provider) in providers {
+        let provider = cq!(provider);
+        // Only run the provider on activation or refresh, or if it changed.
+        let ctx = world.resource::<TooltipContext>();
+        if !refresh
+            && ctx.lazy_content[slot]
+                .as_ref()
+                .is_some_and(|&(old_provider, _)| old_provider == provider)
+        {
+            continue;
+        }
+
+        let content = world
+            .run_system_with(provider.0, target)
+            .ok()
+            .filter(|content| !matches!(content, TooltipContent::Lazy(_)));
+        world.resource_mut::<TooltipContext>().lazy_content[slot] =
+            content.map(|content| (provider, content));
+    }
+
+    // Show a copy of the resolved lazy content.
+    let mut ctx = world.resource_mut::<TooltipContext>();
+    let ctx = &mut *ctx;
+    let contents = [
+        Some(&mut ctx.tooltip.content),
+        ctx.tooltip.companion.as_mut(),
+    ];
+    for (slot, content) in contents.into_iter().enumerate() {
+        if let Some(content) = content
+            && let TooltipContent::Lazy(_) = content
+            && let Some((_, resolved)) = &ctx.lazy_content[slot]
+        {
+            *content = resolved.clone();
+        }
+    }
+}
+
 /// A message written when a tooltip should be shown.
 #[derive(Message)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 struct ShowTooltip;
 
 fn show_tooltip(
+    mut commands: Commands,
     mut ctx: ResMut<TooltipContext>,
     primary: Res<TooltipSettings>,
-    mut text_query: Query<&mut RichText>,
+    theme: Res<TooltipTheme>,
+    mut text_query: Query<(&mut RichText, &mut Node)>,
+    children_query: Query<&Children>,
+    structured_query: Query<(), With<StructuredContentRoot>>,
     mut visibility_query: Query<&mut Visibility>,
+    mut color_query: Query<(&mut BackgroundColor, &mut BorderColor)>,
+    mut variant_applied: Local<bool>,
 ) {
-    let entity = match ctx.tooltip.content {
-        TooltipContent::Primary(ref mut text) => {
-            if let Ok(mut primary_text) = text_query.get_mut(primary.text) {
-                *primary_text = core::mem::take(text);
+    let variant = theme.variant(ctx.tooltip.variant.as_deref());
+
+    // Restyle the primary containers, or restore the base theme after a variant was applied.
+    if variant.is_some() || *variant_applied {
+        *variant_applied = variant.is_some();
+        for container in [primary.container, primary.companion_container] {
+            let (mut background_color, mut border_color) = cq!(color_query.get_mut(container));
+            background_color.0 = theme.background_color(variant);
+            *border_color = BorderColor::all(theme.border_color(variant));
+        }
+    }
+
+    // Show the companion content first, then the main content.
+    let ctx = &mut *ctx;
+    let target = ctx.target;
+    let refresh = ctx.refresh;
+    let direction = ctx.tooltip.direction.unwrap_or(primary.direction);
+
+    // Despawn the instances of templated content that was swapped out.
+    let slots = [Some(&ctx.tooltip.content), ctx.tooltip.companion.as_ref()];
+    for (content, instance) in slots.into_iter().zip(&mut ctx.template_instances) {
+        if !matches!(content, Some(TooltipContent::Template(_)))
+            && let Some(old) = instance.take()
+        {
+            commands.entity(old.entity).try_despawn();
+        }
+    }
+    let contents = ctx
+        .tooltip
+        .companion
+        .iter_mut()
+        .map(|companion| {
+            let container = primary.companion_container;
+            (companion, container, primary.companion_text, COMPANION)
+        })
+        .chain(core::iter::once((
+            &mut ctx.tooltip.content,
+            primary.container,
+            primary.text,
+            MAIN,
+        )));
+    for (content, container, text, slot) in contents {
+        match content {
+            &mut TooltipContent::Custom(id) => {
+                *c!(visibility_query.get_mut(id)) = Visibility::Visible;
+                continue;
+            }
+            TooltipContent::Template(template) => {
+                let instance = &mut ctx.template_instances[slot];
+                // Reuse the previous instance if possible, or despawn it and spawn a new one.
+                let entity = match instance {
+                    Some(instance)
+                        if !refresh
+                            && instance.target == target
+                            && instance.template.ptr_eq(template) =>
+                    {
+                        instance.entity
+                    }
+                    _ => {
+                        if let Some(old) = instance.take() {
+                            commands.entity(old.entity).try_despawn();
+                        }
+                        let entity = template.spawn(&mut commands, target);
+                        *instance = Some(TemplateInstance {
+                            template: template.clone(),
+                            target,
+                            entity,
+                        });
+                        entity
+                    }
+                };
+                commands.entity(entity).try_insert(Visibility::Visible);
+                continue;
             }
-            primary.container
+            // Lazy content that failed to resolve is not displayed.
+            TooltipContent::Lazy(_) | TooltipContent::Localized(_) => continue,
+            _ => {}
         }
-        TooltipContent::Custom(id) => id,
-    };
 
-    *r!(visibility_query.get_mut(entity)) = Visibility::Visible;
+        // Find the previous structured content.
+        let structured_root = children_query
+            .get(container)
+            .into_iter()
+            .flatten()
+            .copied()
+            .find(|&child| structured_query.contains(child));
+        let shown_structured = &mut ctx.shown_structured[slot];
+
+        // Only update the primary tooltip if its content changed.
+        match content {
+            TooltipContent::Primary(content_text) => {
+                if let Some(root) = structured_root {
+                    commands.entity(root).despawn();
+                }
+                *shown_structured = None;
+                if let Ok((mut primary_text, mut node)) = text_query.get_mut(text) {
+                    let mut content_text = core::mem::take(content_text);
+                    theme.apply_to_text(variant, &mut content_text);
+                    content_text.justify = direction.resolve_justify(content_text.justify);
+                    primary_text.set_if_neq(content_text);
+                    if node.display != Display::Flex {
+                        node.display = Display::Flex;
+                    }
+                }
+            }
+            TooltipContent::Structured(structured) => {
+                if structured_root.is_none()
+                    || shown_structured
+                        .as_ref()
+                        .is_none_or(|shown| (&shown.0, shown.1) != (&*structured, direction))
+                    || theme.is_changed()
+                {
+                    if let Some(root) = structured_root {
+                        commands.entity(root).despawn();
+                    }
+                    *shown_structured = Some((structured.clone(), direction));
+                    core::mem::take(structured).spawn(
+                        &mut commands,
+                        container,
+                        &theme.structured,
+                        direction,
+                    );
+                }
+                if let Ok((mut primary_text, mut node)) = text_query.get_mut(text) {
+                    primary_text.set_if_neq(RichText::default());
+                    if node.display != Display::None {
+                        node.display = Display::None;
+                    }
+                }
+            }
+            TooltipContent::Custom(_)
+            | TooltipContent::Template(_)
+            | TooltipContent::Lazy(_)
+            | TooltipContent::Localized(_) => {}
+        }
+
+        *c!(visibility_query.get_mut(container)) = Visibility::Visible;
+    }
 }
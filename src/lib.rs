@@ -41,8 +41,12 @@
 extern crate alloc;
 
 mod context;
+mod localization;
+mod placeholder;
 mod placement;
 mod rich_text;
+mod structured;
+mod theme;
 
 /// Re-exports for commonly used types.
 ///
@@ -53,23 +57,31 @@ mod rich_text;
 /// ```
 pub mod prelude {
     pub use super::{
-        Tooltip, TooltipActivation, TooltipContent, TooltipPlacement, TooltipPlugin,
-        TooltipSettings, TooltipSystems, TooltipTransfer,
-        rich_text::{RichText, TextSection, TextStyle},
+        LocalizedText, Localizer, Placeholder, PlaceholderValue, StructuredContent, Tooltip,
+        TooltipActivation, TooltipClock, TooltipCondition, TooltipContent, TooltipDefaults,
+        TooltipDirection, TooltipDisabled, TooltipGroup, TooltipHotkey, TooltipLocale,
+        TooltipLocalizer, TooltipPlaceholders, TooltipPlacement, TooltipPlugin, TooltipSettings,
+        TooltipStyle, TooltipSuppressor, TooltipSystems, TooltipTemplate, TooltipTheme,
+        TooltipTransfer,
+        rich_text::{InlineImage, RichText, TextSection, TextStyle},
     };
 }
 
+use core::{fmt, time::Duration};
+
 use alloc::{
+    borrow::Cow,
     string::{String, ToString as _},
+    sync::Arc,
     vec::Vec,
 };
 
 use bevy_app::{Plugin, PostUpdate, PreUpdate};
 use bevy_camera::visibility::Visibility;
-use bevy_color::Color;
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
 use bevy_ecs::{
+    bundle::Bundle,
     component::Component,
     entity::Entity,
     entity_disabling::Disabled,
@@ -78,18 +90,25 @@ use bevy_ecs::{
     query::With,
     resource::Resource,
     schedule::{IntoScheduleConfigs as _, SystemSet, common_conditions::resource_changed},
-    system::{Commands, Query, Res},
+    system::{Commands, In, Query, Res, SystemId},
     world::World,
 };
+use bevy_input::{gamepad::GamepadButton, keyboard::KeyCode};
 use bevy_sprite::Anchor;
 use bevy_text::Justify;
 use bevy_transform::TransformSystems;
 use bevy_ui::{
-    BackgroundColor, GlobalZIndex, Interaction, Node, PositionType, UiRect, UiSystems, Val,
+    BackgroundColor, BorderColor, GlobalZIndex, Interaction, Node, PositionType, UiSystems,
 };
 
+pub use localization::{LocalizedText, Localizer, TooltipLocale, TooltipLocalizer};
+pub use placeholder::{Placeholder, PlaceholderValue, TooltipPlaceholders};
 pub use placement::TooltipPlacement;
-pub use rich_text::{RichText, RichTextSystems, TextSection, TextStyle};
+pub use rich_text::{
+    InlineImage, MarkupError, MarkupErrorKind, RichText, RichTextSystems, TextSection, TextStyle,
+};
+pub use structured::StructuredContent;
+pub use theme::{StructuredTheme, TooltipStyle, TooltipTheme};
 
 /// A [`Plugin`] that sets up the tooltip widget system.
 ///
@@ -107,15 +126,42 @@ pub struct TooltipPlugin {
     /// This entity should include all of the required components of [`Node`], along with a
     /// [`RichText`] component, and be a child of [`Self::container`].
     pub text: Entity,
+    /// Set a custom entity for [`TooltipSettings::companion_container`], or spawn the default
+    /// companion container entity if `None`.
+    ///
+    /// This entity has the same requirements as [`Self::container`].
+    pub companion_container: Entity,
+    /// Set a custom entity for [`TooltipSettings::companion_text`], or spawn the default
+    /// companion text entity if `None`.
+    ///
+    /// This entity has the same requirements as [`Self::text`], and should be a child of
+    /// [`Self::companion_container`].
+    pub companion_text: Entity,
     /// Whether or not the tooltip system should initially be enabled.
     pub enabled: bool,
+    /// The initial value for [`TooltipSettings::clock`].
+    pub clock: TooltipClock,
+    /// The initial value for [`TooltipSettings::direction`].
+    pub direction: TooltipDirection,
+    /// The initial [`TooltipTheme`], used to spawn the default container entities.
+    pub theme: TooltipTheme,
 }
 
 impl Plugin for TooltipPlugin {
     fn build(&self, app: &mut bevy_app::App) {
-        let settings =
-            TooltipSettings::new(app.world_mut(), self.container, self.text, self.enabled);
+        let settings = TooltipSettings::new(
+            app.world_mut(),
+            self.container,
+            self.text,
+            self.companion_container,
+            self.companion_text,
+            self.enabled,
+            self.clock,
+            self.direction,
+            &self.theme,
+        );
         app.insert_resource(settings);
+        app.insert_resource(self.theme.clone());
 
         app.configure_sets(
             PreUpdate,
@@ -139,7 +185,14 @@ impl Plugin for TooltipPlugin {
                 .run_if(resource_changed::<TooltipSettings>)
                 .before(TooltipSystems::Content),
         );
-        app.add_plugins((context::plugin, placement::plugin, rich_text::plugin));
+        app.add_plugins((
+            context::plugin,
+            localization::plugin,
+            placeholder::plugin,
+            placement::plugin,
+            rich_text::plugin,
+            theme::plugin,
+        ));
     }
 }
 
@@ -148,7 +201,12 @@ impl Default for TooltipPlugin {
         Self {
             container: Entity::PLACEHOLDER,
             text: Entity::PLACEHOLDER,
+            companion_container: Entity::PLACEHOLDER,
+            companion_text: Entity::PLACEHOLDER,
             enabled: true,
+            clock: TooltipClock::Real,
+            direction: TooltipDirection::LeftToRight,
+            theme: TooltipTheme::default(),
         }
     }
 }
@@ -167,60 +225,149 @@ pub struct TooltipSettings {
     pub container: Entity,
     /// The [`Entity`] ID of the UI node to be used as the primary tooltip's text.
     pub text: Entity,
+    /// The [`Entity`] ID of the UI node to be used as the primary companion tooltip.
+    pub companion_container: Entity,
+    /// The [`Entity`] ID of the UI node to be used as the primary companion tooltip's text.
+    pub companion_text: Entity,
     /// Whether or not tooltips will be displayed.
     pub enabled: bool,
+    /// The clock used to tick tooltip timers.
+    pub clock: TooltipClock,
+    /// The layout direction for tooltips that don't set [`Tooltip::direction`].
+    pub direction: TooltipDirection,
 }
 
 impl TooltipSettings {
-    fn new(world: &mut World, container: Entity, text: Entity, enabled: bool) -> Self {
-        let container = if container != Entity::PLACEHOLDER {
-            container
-        } else {
-            world
-                .spawn((
-                    Name::new("PrimaryTooltip"),
-                    Node {
-                        position_type: PositionType::Absolute,
-                        padding: UiRect::all(Val::Px(8.0)),
-                        ..Default::default()
-                    },
-                    BackgroundColor(Color::srgba(0.106, 0.118, 0.122, 0.9)),
-                    Visibility::Hidden,
-                    GlobalZIndex(999),
-                ))
-                .id()
-        };
-
-        let text = if text != Entity::PLACEHOLDER {
-            text
-        } else {
-            world
-                .spawn((
-                    Name::new("Text"),
-                    Node::default(),
-                    RichText::default(),
-                    ChildOf(container),
-                ))
-                .id()
-        };
+    fn new(
+        world: &mut World,
+        container: Entity,
+        text: Entity,
+        companion_container: Entity,
+        companion_text: Entity,
+        enabled: bool,
+        clock: TooltipClock,
+        direction: TooltipDirection,
+        theme: &TooltipTheme,
+    ) -> Self {
+        let container = spawn_container(world, container, "PrimaryTooltip", theme);
+        let text = spawn_text(world, text, container);
+        let companion_container =
+            spawn_container(world, companion_container, "PrimaryCompanionTooltip", theme);
+        let companion_text = spawn_text(world, companion_text, companion_container);
 
         Self {
             container,
             text,
+            companion_container,
+            companion_text,
             enabled,
+            clock,
+            direction,
         }
     }
 }
 
+/// The clock used to tick tooltip timers.
+///
+/// Defaults to [`Self::Real`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TooltipClock {
+    /// Use [`Time<Real>`](bevy_time::Real), so tooltips keep working while the game is paused.
+    #[default]
+    Real,
+    /// Use [`Time<Virtual>`](bevy_time::Virtual), so tooltips follow the game's speed and pause state.
+    Virtual,
+}
+
+/// The layout direction of a tooltip.
+///
+/// In [`Self::RightToLeft`], the tooltip's [`TooltipPlacement`] is mirrored horizontally (e.g.
+/// [`TooltipPlacement::CURSOR`] places the tooltip to the left of the cursor), and the primary
+/// tooltip's [`Justify::Start`] (the default) and [`Justify::End`] text justification become
+/// right and left justification. Explicit [`Justify::Left`] and [`Justify::Right`] are kept.
+///
+/// Defaults to [`Self::LeftToRight`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TooltipDirection {
+    /// Lay out tooltips for left-to-right languages.
+    #[default]
+    LeftToRight,
+    /// Lay out tooltips for right-to-left languages (e.g. Arabic and Hebrew).
+    RightToLeft,
+}
+
+impl TooltipDirection {
+    /// Resolve [`Justify::Start`] and [`Justify::End`] to left or right justification.
+    pub(crate) fn resolve_justify(self, justify: Justify) -> Justify {
+        match (self, justify) {
+            (Self::LeftToRight, Justify::Start) | (Self::RightToLeft, Justify::End) => {
+                Justify::Left
+            }
+            (Self::LeftToRight, Justify::End) | (Self::RightToLeft, Justify::Start) => {
+                Justify::Right
+            }
+            _ => justify,
+        }
+    }
+}
+
+fn spawn_container(
+    world: &mut World,
+    container: Entity,
+    name: &'static str,
+    theme: &TooltipTheme,
+) -> Entity {
+    if container != Entity::PLACEHOLDER {
+        return container;
+    }
+
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        ..Default::default()
+    };
+    theme.apply_to_node(&mut node);
+
+    world
+        .spawn((
+            Name::new(name),
+            node,
+            BackgroundColor(theme.background_color),
+            BorderColor::all(theme.border_color),
+            theme.box_shadow(),
+            Visibility::Hidden,
+            GlobalZIndex(999),
+        ))
+        .id()
+}
+
+fn spawn_text(world: &mut World, text: Entity, container: Entity) -> Entity {
+    if text != Entity::PLACEHOLDER {
+        return text;
+    }
+
+    world
+        .spawn((
+            Name::new("Text"),
+            Node::default(),
+            RichText::default(),
+            ChildOf(container),
+        ))
+        .id()
+}
+
 fn sync_tooltip_settings(mut commands: Commands, settings: Res<TooltipSettings>) {
-    if settings.enabled {
-        commands
-            .entity(settings.container)
-            .remove_recursive::<Children, Disabled>();
-    } else {
-        commands
-            .entity(settings.container)
-            .insert_recursive::<Children>(Disabled);
+    for container in [settings.container, settings.companion_container] {
+        if settings.enabled {
+            commands
+                .entity(container)
+                .remove_recursive::<Children, Disabled>();
+        } else {
+            commands
+                .entity(container)
+                .insert_recursive::<Children>(Disabled);
+        }
     }
 }
 
@@ -231,8 +378,13 @@ fn tooltips_enabled(
     settings.enabled && !disabled_query.contains(settings.container)
 }
 
-// TODO: Animation, wedge (like a speech bubble), easier content customization / icons.
+// TODO: Animation, wedge (like a speech bubble), easier content customization.
 /// A [`Component`] that specifies a tooltip to be displayed on hover.
+///
+/// The tooltip will also be displayed while its entity has [`InputFocus`](bevy_input_focus::InputFocus)
+/// and [`InputFocusVisible`](bevy_input_focus::InputFocusVisible) is set, e.g. for keyboard or
+/// gamepad navigation. In that case, cursor placements will fall back to a fixed placement below
+/// the entity.
 #[derive(Component, Clone, Debug)]
 #[require(Node, Interaction)]
 #[cfg_attr(
@@ -243,6 +395,22 @@ fn tooltips_enabled(
 pub struct Tooltip {
     /// The tooltip content to display.
     pub content: TooltipContent,
+    /// Additional tooltip content to display alongside the main tooltip.
+    pub companion: Option<TooltipContent>,
+    /// Detailed tooltip content to swap in while a hotkey is held.
+    pub details: Option<TooltipDetails>,
+    /// The interval at which to refresh [`TooltipContent::Lazy`] and [`TooltipContent::Template`]
+    /// content while the tooltip is open, or `None` to only compute it on activation.
+    ///
+    /// Changes to the content in this component are always displayed while the tooltip is open.
+    pub refresh: Option<Duration>,
+    /// The layout direction, or `None` to use [`TooltipSettings::direction`].
+    pub direction: Option<TooltipDirection>,
+    /// The name of the [`TooltipStyle`] variant to apply to the primary tooltip.
+    ///
+    /// Variants are registered in [`TooltipTheme::variants`]. Unregistered names fall back to the
+    /// base theme.
+    pub variant: Option<Cow<'static, str>>,
     /// How the tooltip will be positioned.
     pub placement: TooltipPlacement,
     /// The conditions for activating the tooltip.
@@ -251,6 +419,8 @@ pub struct Tooltip {
     pub dismissal: TooltipDismissal,
     /// The conditions for skipping the next tooltip's activation delay.
     pub transfer: TooltipTransfer,
+    /// The fields to inherit from the nearest ancestors with [`TooltipDefaults`].
+    pub inherit: TooltipInherit,
 }
 
 impl Tooltip {
@@ -258,10 +428,16 @@ impl Tooltip {
     pub fn fixed(placement: Anchor, content: impl Into<TooltipContent>) -> Self {
         Self {
             content: content.into(),
+            companion: None,
+            details: None,
+            refresh: None,
+            direction: None,
+            variant: None,
             placement: placement.into(),
             activation: TooltipActivation::IMMEDIATE,
             dismissal: TooltipDismissal::NONE,
             transfer: TooltipTransfer::SHORT,
+            inherit: TooltipInherit::NONE,
         }
     }
 
@@ -269,10 +445,16 @@ impl Tooltip {
     pub fn cursor(content: impl Into<TooltipContent>) -> Self {
         Self {
             content: content.into(),
+            companion: None,
+            details: None,
+            refresh: None,
+            direction: None,
+            variant: None,
             placement: TooltipPlacement::CURSOR,
             activation: TooltipActivation::IDLE,
             dismissal: TooltipDismissal::ON_CLICK,
             transfer: TooltipTransfer::NONE,
+            inherit: TooltipInherit::NONE,
         }
     }
 
@@ -280,16 +462,33 @@ impl Tooltip {
     pub fn follow_cursor(content: impl Into<TooltipContent>) -> Self {
         Self {
             content: content.into(),
+            companion: None,
+            details: None,
+            refresh: None,
+            direction: None,
+            variant: None,
             placement: TooltipPlacement::FOLLOW_CURSOR,
             activation: TooltipActivation::IMMEDIATE,
             dismissal: TooltipDismissal::NONE,
             transfer: TooltipTransfer::NONE,
+            inherit: TooltipInherit::NONE,
+        }
+    }
+
+    /// Create a new `Tooltip` that inherits its configuration from its ancestors.
+    ///
+    /// Any fields that aren't provided by a [`TooltipDefaults`] ancestor will fall back to the
+    /// values from [`Self::cursor`].
+    pub fn inherited(content: impl Into<TooltipContent>) -> Self {
+        Self {
+            inherit: TooltipInherit::ALL,
+            ..Self::cursor(content)
         }
     }
 
     /// Change the text justification.
     ///
-    /// NOTE: This does nothing for custom tooltips.
+    /// NOTE: This only affects [`TooltipContent::Primary`].
     pub fn with_justify(mut self, justify: Justify) -> Self {
         // TODO: Warn otherwise?
         if let TooltipContent::Primary(text) = &mut self.content {
@@ -298,39 +497,219 @@ impl Tooltip {
         self
     }
 
+    /// Set companion content to display alongside the main tooltip.
+    ///
+    /// The companion will be placed next to the main tooltip, on the side determined by
+    /// [`TooltipPlacement::anchor_point`], and flipped to the other side if it doesn't fit
+    /// within the window.
+    pub fn with_companion(mut self, companion: impl Into<TooltipContent>) -> Self {
+        self.companion = Some(companion.into());
+        self
+    }
+
+    /// Set detailed content to swap in while a hotkey is held.
+    ///
+    /// Swapping between the regular and detailed content does not reset the activation state.
+    pub fn with_details(
+        mut self,
+        hotkey: impl Into<TooltipHotkey>,
+        content: impl Into<TooltipContent>,
+    ) -> Self {
+        self.details = Some(TooltipDetails {
+            hotkey: hotkey.into(),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Set the interval at which to refresh lazy and templated content while the tooltip is open.
+    ///
+    /// Use [`Duration::ZERO`] to refresh every frame.
+    pub fn with_refresh(mut self, interval: Duration) -> Self {
+        self.refresh = Some(interval);
+        self
+    }
+
+    /// Set the layout direction, overriding [`TooltipSettings::direction`].
+    pub fn with_direction(mut self, direction: TooltipDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the name of the [`TooltipStyle`] variant to apply to the primary tooltip.
+    pub fn with_variant(mut self, variant: impl Into<Cow<'static, str>>) -> Self {
+        self.variant = Some(variant.into());
+        self
+    }
+
     /// Set a custom [`TooltipPlacement`].
     pub fn with_placement(mut self, placement: impl Into<TooltipPlacement>) -> Self {
         self.placement = placement.into();
+        self.inherit.placement = false;
         self
     }
 
     /// Set a custom [`TooltipActivation`].
     pub fn with_activation(mut self, activation: impl Into<TooltipActivation>) -> Self {
         self.activation = activation.into();
+        self.inherit.activation = false;
         self
     }
 
     /// Set a custom [`TooltipDismissal`].
     pub fn with_dismissal(mut self, dismissal: impl Into<TooltipDismissal>) -> Self {
         self.dismissal = dismissal.into();
+        self.inherit.dismissal = false;
         self
     }
 
     /// Set a custom [`TooltipTransfer`].
     pub fn with_transfer(mut self, transfer: impl Into<TooltipTransfer>) -> Self {
         self.transfer = transfer.into();
+        self.inherit.transfer = false;
+        self
+    }
+}
+
+/// The [`Tooltip`] fields to inherit from the nearest ancestors with [`TooltipDefaults`].
+///
+/// Defaults to [`Self::NONE`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipInherit {
+    /// Whether to inherit [`Tooltip::placement`].
+    pub placement: bool,
+    /// Whether to inherit [`Tooltip::activation`].
+    pub activation: bool,
+    /// Whether to inherit [`Tooltip::dismissal`].
+    pub dismissal: bool,
+    /// Whether to inherit [`Tooltip::transfer`].
+    pub transfer: bool,
+}
+
+impl TooltipInherit {
+    /// Don't inherit any fields.
+    pub const NONE: Self = Self {
+        placement: false,
+        activation: false,
+        dismissal: false,
+        transfer: false,
+    };
+
+    /// Inherit all fields.
+    pub const ALL: Self = Self {
+        placement: true,
+        activation: true,
+        dismissal: true,
+        transfer: true,
+    };
+}
+
+impl Default for TooltipInherit {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// A [`Component`] that provides default configuration for the [`Tooltip`]s of its entity and
+/// descendants.
+///
+/// Only the fields enabled in [`Tooltip::inherit`] will be inherited. Each field is inherited from
+/// the nearest ancestor that provides it.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipDefaults {
+    /// The default [`Tooltip::placement`].
+    pub placement: Option<TooltipPlacement>,
+    /// The default [`Tooltip::activation`].
+    pub activation: Option<TooltipActivation>,
+    /// The default [`Tooltip::dismissal`].
+    pub dismissal: Option<TooltipDismissal>,
+    /// The default [`Tooltip::transfer`].
+    pub transfer: Option<TooltipTransfer>,
+}
+
+impl TooltipDefaults {
+    /// Set a default [`TooltipPlacement`].
+    pub fn with_placement(mut self, placement: impl Into<TooltipPlacement>) -> Self {
+        self.placement = Some(placement.into());
+        self
+    }
+
+    /// Set a default [`TooltipActivation`].
+    pub fn with_activation(mut self, activation: impl Into<TooltipActivation>) -> Self {
+        self.activation = Some(activation.into());
+        self
+    }
+
+    /// Set a default [`TooltipDismissal`].
+    pub fn with_dismissal(mut self, dismissal: impl Into<TooltipDismissal>) -> Self {
+        self.dismissal = Some(dismissal.into());
+        self
+    }
+
+    /// Set a default [`TooltipTransfer`].
+    pub fn with_transfer(mut self, transfer: impl Into<TooltipTransfer>) -> Self {
+        self.transfer = Some(transfer.into());
         self
     }
 }
 
+/// A marker [`Component`] that prevents the [`Tooltip`] on its entity from activating.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipDisabled;
+
+/// A marker [`Component`] that suppresses all tooltips while any entity has it.
+///
+/// This can be used to temporarily block tooltips during exclusive interactions like
+/// drag-and-drop. Unlike [`TooltipSettings::enabled`], this does not disable the primary tooltip
+/// entities.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipSuppressor;
+
+/// A [`Component`] that prevents the [`Tooltip`] on its entity from activating unless a
+/// condition system returns `true`.
+///
+/// The condition system receives the tooltip's entity as input, and is only run while the
+/// entity is being interacted with or focused.
+#[derive(Component, Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(opaque, Component, Clone, Debug)
+)]
+pub struct TooltipCondition(pub SystemId<In<Entity>, bool>);
+
 /// Tooltip content to be displayed.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub enum TooltipContent {
     /// Display the primary tooltip with custom [`RichText`].
     Primary(RichText),
+    /// Display the primary tooltip with [`StructuredContent`].
+    Structured(StructuredContent),
     /// Display a fully custom entity as the tooltip.
     Custom(Entity),
+    /// Display a custom entity spawned from a [`TooltipTemplate`] when the tooltip activates.
+    Template(TooltipTemplate),
+    /// Display the content returned by a [`TooltipProvider`] when the tooltip activates.
+    Lazy(TooltipProvider),
+    /// Display the primary tooltip with [`LocalizedText`] translated by the [`TooltipLocalizer`].
+    Localized(LocalizedText),
 }
 
 impl From<&str> for TooltipContent {
@@ -350,7 +729,7 @@ impl From<String> for TooltipContent {
 
 impl From<TextSection> for TooltipContent {
     fn from(value: TextSection) -> Self {
-        Self::Primary(RichText::from_section(value.value, value.style))
+        Self::Primary(value.into())
     }
 }
 
@@ -366,73 +745,304 @@ impl From<RichText> for TooltipContent {
     }
 }
 
+impl From<StructuredContent> for TooltipContent {
+    fn from(value: StructuredContent) -> Self {
+        Self::Structured(value)
+    }
+}
+
 impl From<Entity> for TooltipContent {
     fn from(value: Entity) -> Self {
         Self::Custom(value)
     }
 }
 
+impl From<TooltipTemplate> for TooltipContent {
+    fn from(value: TooltipTemplate) -> Self {
+        Self::Template(value)
+    }
+}
+
+impl From<TooltipProvider> for TooltipContent {
+    fn from(value: TooltipProvider) -> Self {
+        Self::Lazy(value)
+    }
+}
+
+impl From<LocalizedText> for TooltipContent {
+    fn from(value: LocalizedText) -> Self {
+        Self::Localized(value)
+    }
+}
+
+impl From<SystemId<In<Entity>, TooltipContent>> for TooltipContent {
+    fn from(value: SystemId<In<Entity>, TooltipContent>) -> Self {
+        Self::Lazy(TooltipProvider(value))
+    }
+}
+
+/// A one-shot system that computes tooltip content from the target entity when the tooltip
+/// activates.
+///
+/// The system is run again when the tooltip activates for a new target, or when the provider
+/// changes (e.g. by swapping in [`Tooltip::details`]). A provider that returns
+/// [`TooltipContent::Lazy`] or fails to run will not display any content.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use pyri_tooltip::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn health_tooltip(In(target): In<Entity>, health_query: Query<&Health>) -> TooltipContent {
+///     let health = health_query.get(target).map_or(0, |health| health.0);
+///     format!("Health: {health}").into()
+/// }
+///
+/// fn spawn_tooltip(world: &mut World) {
+///     let provider = world.register_system(health_tooltip);
+///     world.spawn((Health(10), Node::default(), Tooltip::cursor(provider)));
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(opaque, Clone, PartialEq, Debug)
+)]
+pub struct TooltipProvider(pub SystemId<In<Entity>, TooltipContent>);
+
+/// A template that spawns custom tooltip content when the tooltip activates.
+///
+/// Unlike [`TooltipContent::Custom`], this doesn't require a pre-spawned entity per tooltip. The
+/// spawned entity is reused while the tooltip stays active on the same target, and despawned when
+/// the tooltip hides or switches to other content.
+///
+/// As with [`TooltipContent::Custom`], the spawned entity should be a root UI node with
+/// [`PositionType::Absolute`] and a high [`GlobalZIndex`] so it's displayed above the rest of the
+/// UI.
+///
+/// To spawn a scene, spawn an entity with a scene root component from [`TooltipTemplate::new`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use pyri_tooltip::prelude::*;
+///
+/// let template = TooltipTemplate::new(|commands, target| {
+///     commands
+///         .spawn((
+///             Node {
+///                 position_type: PositionType::Absolute,
+///                 ..default()
+///             },
+///             GlobalZIndex(999),
+///             BackgroundColor(Color::BLACK),
+///             children![Text::new(format!("Target: {target}"))],
+///         ))
+///         .id()
+/// });
+/// let tooltip = Tooltip::cursor(template);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(opaque, Clone, Debug)
+)]
+pub struct TooltipTemplate(Arc<dyn Fn(&mut Commands, Entity) -> Entity + Send + Sync>);
+
+impl TooltipTemplate {
+    /// Create a new `TooltipTemplate` from a function that spawns the tooltip content for a
+    /// target entity and returns the spawned entity.
+    pub fn new(spawn: impl Fn(&mut Commands, Entity) -> Entity + Send + Sync + 'static) -> Self {
+        Self(Arc::new(spawn))
+    }
+
+    /// Create a new `TooltipTemplate` that spawns a clone of a bundle.
+    pub fn bundle(bundle: impl Bundle + Clone) -> Self {
+        Self::new(move |commands, _| commands.spawn(bundle.clone()).id())
+    }
+
+    /// Spawn the tooltip content for a target entity.
+    pub(crate) fn spawn(&self, commands: &mut Commands, target: Entity) -> Entity {
+        (self.0)(commands, target)
+    }
+
+    /// Whether two templates are the same template.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for TooltipTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TooltipTemplate").finish_non_exhaustive()
+    }
+}
+
+/// Detailed tooltip content to be displayed while a hotkey is held.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipDetails {
+    /// The hotkey that must be held to display the detailed content.
+    pub hotkey: TooltipHotkey,
+    /// The detailed content to display.
+    pub content: TooltipContent,
+}
+
+/// A key or gamepad button used to control tooltips.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TooltipHotkey {
+    /// A keyboard key.
+    Key(KeyCode),
+    /// A button on any connected gamepad.
+    GamepadButton(GamepadButton),
+}
+
+impl From<KeyCode> for TooltipHotkey {
+    fn from(value: KeyCode) -> Self {
+        Self::Key(value)
+    }
+}
+
+impl From<GamepadButton> for TooltipHotkey {
+    fn from(value: GamepadButton) -> Self {
+        Self::GamepadButton(value)
+    }
+}
+
 /// Tooltip activation conditions.
 ///
 /// Defaults to [`Self::IMMEDIATE`].
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TooltipActivation {
-    /// The hover duration before the tooltip will activate (in milliseconds).
-    pub delay: u16,
+    /// The hover duration before the tooltip will activate.
+    pub delay: Duration,
     /// Whether to reset the activation delay timer whenever the cursor moves.
     pub reset_delay_on_cursor_move: bool,
+    /// The distance the cursor can move from its resting point without resetting the activation
+    /// delay timer, when [`Self::reset_delay_on_cursor_move`] is enabled.
+    pub cursor_move_tolerance: f32,
+    /// The cursor speed above which the activation delay timer will be reset (in logical pixels
+    /// per second).
+    ///
+    /// If [`Self::reset_delay_on_cursor_move`] is also enabled, the timer will only be reset when
+    /// the cursor is both outside of [`Self::cursor_move_tolerance`] and faster than this speed.
+    pub max_cursor_speed: f32,
+    /// Whether the target must be pressed and held for the activation delay (e.g. on touch screens).
+    ///
+    /// Releasing or dragging away from the target will dismiss the tooltip, and
+    /// [`TooltipDismissal::on_click`] will be ignored.
+    pub long_press: bool,
+    /// A hotkey that must be held for the activation delay, or `None` to not require a hotkey.
+    pub hotkey: Option<TooltipHotkey>,
+    /// Whether pressing [`Self::hotkey`] should toggle it on / off instead of requiring it to be held.
+    ///
+    /// The toggle only registers while the target is being interacted with, and is reset when
+    /// switching to a new target.
+    pub hotkey_toggle: bool,
 }
 
 impl TooltipActivation {
     /// Show tooltip immediately on hover.
     pub const IMMEDIATE: Self = Self {
-        delay: 0,
+        delay: Duration::ZERO,
         reset_delay_on_cursor_move: false,
+        cursor_move_tolerance: 0.0,
+        max_cursor_speed: f32::INFINITY,
+        long_press: false,
+        hotkey: None,
+        hotkey_toggle: false,
     };
 
     /// Show tooltip after a short while.
     pub const SHORT_DELAY: Self = Self {
-        delay: 200,
-        reset_delay_on_cursor_move: false,
+        delay: Duration::from_millis(200),
+        ..Self::IMMEDIATE
     };
 
     /// Show tooltip after a while.
     pub const DELAY: Self = Self {
-        delay: 400,
-        reset_delay_on_cursor_move: false,
+        delay: Duration::from_millis(400),
+        ..Self::IMMEDIATE
     };
 
     /// Show tooltip after a long while.
     pub const LONG_DELAY: Self = Self {
-        delay: 600,
-        reset_delay_on_cursor_move: false,
+        delay: Duration::from_millis(600),
+        ..Self::IMMEDIATE
     };
 
     /// Show tooltip after the cursor stays idle for a short while.
     pub const SHORT_IDLE: Self = Self {
-        delay: 200,
+        delay: Duration::from_millis(200),
         reset_delay_on_cursor_move: true,
+        ..Self::IMMEDIATE
     };
 
     /// Show tooltip after the cursor stays idle for a while.
     pub const IDLE: Self = Self {
-        delay: 400,
+        delay: Duration::from_millis(400),
         reset_delay_on_cursor_move: true,
+        ..Self::IMMEDIATE
     };
 
     /// Show tooltip after the cursor stays idle for a long while.
     pub const LONG_IDLE: Self = Self {
-        delay: 600,
+        delay: Duration::from_millis(600),
+        reset_delay_on_cursor_move: true,
+        ..Self::IMMEDIATE
+    };
+
+    /// Show tooltip after the cursor slows down or rests within a small radius for a while.
+    pub const INTENT: Self = Self {
+        delay: Duration::from_millis(300),
         reset_delay_on_cursor_move: true,
+        cursor_move_tolerance: 4.0,
+        max_cursor_speed: 100.0,
+        ..Self::IMMEDIATE
+    };
+
+    /// Show tooltip after the target is pressed and held for a while.
+    pub const LONG_PRESS: Self = Self {
+        delay: Duration::from_millis(500),
+        long_press: true,
+        ..Self::IMMEDIATE
     };
+
+    /// Require a hotkey to be held to show the tooltip.
+    pub const fn with_hotkey(mut self, hotkey: TooltipHotkey) -> Self {
+        self.hotkey = Some(hotkey);
+        self.hotkey_toggle = false;
+        self
+    }
+
+    /// Require a hotkey to be toggled on to show the tooltip.
+    pub const fn with_hotkey_toggle(mut self, hotkey: TooltipHotkey) -> Self {
+        self.hotkey = Some(hotkey);
+        self.hotkey_toggle = true;
+        self
+    }
 }
 
 impl From<u16> for TooltipActivation {
     fn from(value: u16) -> Self {
+        Duration::from_millis(u64::from(value)).into()
+    }
+}
+
+impl From<Duration> for TooltipActivation {
+    fn from(value: Duration) -> Self {
         Self {
             delay: value,
-            reset_delay_on_cursor_move: false,
+            ..Self::IMMEDIATE
         }
     }
 }
@@ -453,6 +1063,24 @@ pub struct TooltipDismissal {
     pub on_distance: f32,
     /// Whether the tooltip should be dismissed on click.
     pub on_click: bool,
+    /// A key or gamepad button that will dismiss the tooltip when pressed.
+    pub on_key_press: Option<TooltipHotkey>,
+    /// Whether the tooltip should be dismissed when any key is pressed.
+    ///
+    /// The tooltip's own [activation hotkey](TooltipActivation::hotkey) and
+    /// [details hotkey](TooltipDetails::hotkey) will be ignored.
+    pub on_any_key_press: bool,
+    /// Whether the tooltip should be dismissed when an ancestor of its target entity is scrolled.
+    pub on_scroll: bool,
+    /// Whether the tooltip should be dismissed when the window loses focus.
+    pub on_window_blur: bool,
+    /// The display duration after which the tooltip will be dismissed.
+    pub on_timeout: Option<Duration>,
+    /// The idle duration after which a dismissed tooltip will be re-armed.
+    ///
+    /// The cursor must move after the tooltip is dismissed, and then stay idle for this duration.
+    /// The tooltip will then go through its [activation delay](TooltipActivation::delay) again.
+    pub rearm_on_idle: Option<Duration>,
 }
 
 impl TooltipDismissal {
@@ -460,12 +1088,36 @@ impl TooltipDismissal {
     pub const NONE: Self = Self {
         on_distance: f32::INFINITY,
         on_click: false,
+        on_key_press: None,
+        on_any_key_press: false,
+        on_scroll: false,
+        on_window_blur: false,
+        on_timeout: None,
+        rearm_on_idle: None,
     };
 
     /// Dismiss tooltip on click.
     pub const ON_CLICK: Self = Self {
         on_distance: f32::INFINITY,
         on_click: true,
+        on_key_press: None,
+        on_any_key_press: false,
+        on_scroll: false,
+        on_window_blur: false,
+        on_timeout: None,
+        rearm_on_idle: None,
+    };
+
+    /// Dismiss tooltip on pressing escape.
+    pub const ON_ESCAPE: Self = Self {
+        on_distance: f32::INFINITY,
+        on_click: false,
+        on_key_press: Some(TooltipHotkey::Key(KeyCode::Escape)),
+        on_any_key_press: false,
+        on_scroll: false,
+        on_window_blur: false,
+        on_timeout: None,
+        rearm_on_idle: None,
     };
 }
 
@@ -479,6 +1131,8 @@ impl Default for TooltipDismissal {
 ///
 /// When a transfer occurs, the next tooltip's [activation delay](TooltipActivation::delay) will be skipped.
 ///
+/// See [`TooltipGroup`] for an alternative that doesn't require picking group and layer numbers.
+///
 /// Defaults to [`Self::NONE`].
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
@@ -487,8 +1141,8 @@ pub struct TooltipTransfer {
     pub group: Option<i8>,
     /// Only transfer to elements within the same layer or lower.
     pub layer: i8,
-    /// Only transfer within this duration after the cursor moves away from the old target (in milliseconds).
-    pub timeout: u16,
+    /// Only transfer within this duration after the cursor moves away from the old target.
+    pub timeout: Duration,
     /// Only transfer if the old tooltip was active.
     pub from_active: bool,
 }
@@ -498,7 +1152,7 @@ impl TooltipTransfer {
     pub const NONE: Self = Self {
         group: None,
         layer: 0,
-        timeout: 0,
+        timeout: Duration::from_millis(0),
         from_active: true,
     };
 
@@ -506,13 +1160,19 @@ impl TooltipTransfer {
     pub const SHORT: Self = Self {
         group: Some(0),
         layer: 0,
-        timeout: 100,
+        timeout: Duration::from_millis(100),
         from_active: true,
     };
 }
 
 impl From<u16> for TooltipTransfer {
     fn from(value: u16) -> Self {
+        Duration::from_millis(u64::from(value)).into()
+    }
+}
+
+impl From<Duration> for TooltipTransfer {
+    fn from(value: Duration) -> Self {
         Self {
             group: Some(0),
             layer: 0,
@@ -528,6 +1188,63 @@ impl Default for TooltipTransfer {
     }
 }
 
+/// A [`Component`] that groups the [`Tooltip`]s of its entity and descendants.
+///
+/// Once a tooltip in the group has been activated, the group becomes warm, and other tooltips in
+/// the group will skip their activation delay until the group cools down. The nearest group takes
+/// precedence over [`TooltipTransfer`].
+///
+/// Defaults to [`Self::SHORT`].
+#[derive(Component, Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipGroup {
+    /// The activation delay for tooltips in the group while it's cold, or `None` to use each
+    /// tooltip's own [activation delay](TooltipActivation::delay).
+    pub warmup: Option<Duration>,
+    /// The duration the group stays warm after the cursor moves away from its tooltips.
+    pub cooldown: Duration,
+    /// Only stay warm if the old tooltip was active.
+    pub from_active: bool,
+}
+
+impl TooltipGroup {
+    /// Short-duration tooltip group.
+    pub const SHORT: Self = Self {
+        warmup: None,
+        cooldown: Duration::from_millis(100),
+        from_active: true,
+    };
+
+    /// Long-duration tooltip group.
+    pub const LONG: Self = Self {
+        warmup: None,
+        cooldown: Duration::from_millis(500),
+        from_active: true,
+    };
+
+    /// Set a custom warm-up delay.
+    pub const fn with_warmup(mut self, warmup: Duration) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Set a custom cooldown duration.
+    pub const fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Default for TooltipGroup {
+    fn default() -> Self {
+        Self::SHORT
+    }
+}
+
 /// A [`SystemSet`] for tooltip systems.
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum TooltipSystems {
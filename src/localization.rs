@@ -0,0 +1,194 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use core::fmt;
+
+use bevy_app::App;
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::{
+    change_detection::DetectChanges as _,
+    resource::Resource,
+    system::{Local, Res, ResMut},
+};
+
+use crate::{
+    TooltipContent, context::TooltipContext, placeholder::PlaceholderValue, rich_text::RichText,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TooltipLocale>();
+}
+
+/// A [`Resource`] that contains the active locale passed to the [`TooltipLocalizer`].
+///
+/// [`TooltipContent::Localized`] is translated again when this resource changes.
+#[derive(Resource, Clone, Default, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct TooltipLocale(pub Cow<'static, str>);
+
+impl TooltipLocale {
+    /// Create a new `TooltipLocale` from a locale identifier (e.g. `"en-US"`).
+    pub fn new(locale: impl Into<Cow<'static, str>>) -> Self {
+        Self(locale.into())
+    }
+}
+
+/// A [`Resource`] that translates [`TooltipContent::Localized`] with a [`Localizer`].
+///
+/// Without this resource, localized content displays its key.
+///
+/// [`TooltipContent::Localized`] is translated again when this resource changes.
+#[derive(Resource)]
+pub struct TooltipLocalizer(pub Box<dyn Localizer>);
+
+impl TooltipLocalizer {
+    /// Create a new `TooltipLocalizer` from a [`Localizer`].
+    pub fn new(localizer: impl Localizer) -> Self {
+        Self(Box::new(localizer))
+    }
+}
+
+impl fmt::Debug for TooltipLocalizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TooltipLocalizer").finish_non_exhaustive()
+    }
+}
+
+/// A pluggable translation backend for [`TooltipContent::Localized`], stored in
+/// [`TooltipLocalizer`].
+///
+/// This trait is implemented for closures with the same signature as [`Self::localize`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use pyri_tooltip::prelude::*;
+///
+/// fn localize(locale: &str, text: &LocalizedText) -> Option<RichText> {
+///     let translation = match (locale, text.key.as_ref()) {
+///         ("en", "potion.heal") => "Heals {amount} HP",
+///         ("fr", "potion.heal") => "Soigne {amount} PV",
+///         _ => return None,
+///     };
+///     Some(translation.into())
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.insert_resource(TooltipLocale::new("fr"));
+///     app.insert_resource(TooltipLocalizer::new(localize));
+/// }
+///
+/// fn spawn_tooltip(mut commands: Commands) {
+///     let text = LocalizedText::new("potion.heal").with_arg("amount", 25);
+///     commands.spawn(Tooltip::cursor(text));
+/// }
+/// ```
+pub trait Localizer: Send + Sync + 'static {
+    /// Translate the localized text into the given locale, or return `None` if there's no
+    /// translation.
+    ///
+    /// The returned text can contain `{name}` placeholders for the [`LocalizedText::args`] or the
+    /// [`TooltipPlaceholders`](crate::TooltipPlaceholders), and any [`TextStyle`](crate::TextStyle)
+    /// fields left at their defaults are styled by the [`TooltipTheme`](crate::TooltipTheme).
+    fn localize(&self, locale: &str, text: &LocalizedText) -> Option<RichText>;
+}
+
+impl<F> Localizer for F
+where
+    F: Fn(&str, &LocalizedText) -> Option<RichText> + Send + Sync + 'static,
+{
+    fn localize(&self, locale: &str, text: &LocalizedText) -> Option<RichText> {
+        self(locale, text)
+    }
+}
+
+/// A localization key with arguments, translated by the [`TooltipLocalizer`] when the tooltip is
+/// shown.
+///
+/// Each `{name}` in the translation is replaced with the argument with that name, or else the
+/// registered placeholder with that name, using the same syntax and formatting options as
+/// [`TooltipPlaceholders`](crate::TooltipPlaceholders). If there's no translation, the key itself
+/// is displayed.
+#[derive(Clone, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct LocalizedText {
+    /// The localization key.
+    pub key: Cow<'static, str>,
+    /// The named arguments for the translation.
+    pub args: Vec<(Cow<'static, str>, PlaceholderValue)>,
+}
+
+impl LocalizedText {
+    /// Create a new `LocalizedText` from a localization key.
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Add a named argument.
+    pub fn with_arg(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<PlaceholderValue>,
+    ) -> Self {
+        self.args.push((name.into(), value.into()));
+        self
+    }
+
+    /// The value of the argument with the given name, if any.
+    pub fn arg(&self, name: &str) -> Option<&PlaceholderValue> {
+        self.args
+            .iter()
+            .find_map(|(arg_name, value)| (arg_name == name).then_some(value))
+    }
+}
+
+/// Translate the localized content of the current tooltip.
+pub(crate) fn resolve_localized_content(
+    mut ctx: ResMut<TooltipContext>,
+    locale: Res<TooltipLocale>,
+    localizer: Option<Res<TooltipLocalizer>>,
+    mut translations: Local<[Option<(LocalizedText, RichText)>; 2]>,
+) {
+    let retranslate = locale.is_changed()
+        || localizer
+            .as_ref()
+            .is_some_and(|localizer| localizer.is_changed());
+    let ctx = &mut *ctx;
+    let contents = [
+        Some(&mut ctx.tooltip.content),
+        ctx.tooltip.companion.as_mut(),
+    ];
+    let slots = contents.into_iter().zip(translations.iter_mut());
+    for ((content, translation), args) in slots.zip(&mut ctx.localized_args) {
+        args.clear();
+        let Some(content) = content else {
+            continue;
+        };
+        let TooltipContent::Localized(text) = content else {
+            continue;
+        };
+
+        // Only translate the text if it or the locale changed.
+        let translated = match translation {
+            Some((old_text, translated)) if !retranslate && old_text == text => translated.clone(),
+            _ => {
+                let translated = localizer
+                    .as_ref()
+                    .and_then(|localizer| localizer.0.localize(&locale.0, text))
+                    .unwrap_or_else(|| RichText::from(text.key.as_ref()));
+                *translation = Some((text.clone(), translated.clone()));
+                translated
+            }
+        };
+        // The arguments are filled in along with the placeholders.
+        args.clone_from(&text.args);
+        *content = TooltipContent::Primary(translated);
+    }
+}
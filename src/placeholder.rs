@@ -0,0 +1,450 @@
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+
+use bevy_app::App;
+use bevy_color::Color;
+use bevy_ecs::{
+    entity::Entity,
+    resource::Resource,
+    system::{In, SystemId},
+    world::World,
+};
+use bevy_platform::collections::HashMap;
+
+use crate::{
+    TooltipContent,
+    context::TooltipContext,
+    rich_text::{RichText, TextSection},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TooltipPlaceholders>();
+}
+
+/// A [`Resource`] that maps placeholder names to [`Placeholder`] resolvers.
+///
+/// Each `{name}` in the [`TextSection`] values of [`TooltipContent::Primary`] and
+/// [`TooltipContent::Structured`] is replaced with the value of the placeholder with that name
+/// when the tooltip is shown. Unknown placeholders are left as-is, and `{{` and `}}` always
+/// display literal braces, even if no placeholders are registered.
+///
+/// A placeholder can include formatting options after a colon, in any order:
+///
+/// - `+`: Always display the sign, e.g. `{amount:+}` displays `+5`.
+/// - `.N`: Display `N` decimal places, e.g. `{amount:.1}` displays `5.0`.
+/// - `%`: Display as a percentage, e.g. `{chance:%}` displays `25%` for `0.25`. Without `.N`,
+///   percentages are rounded to at most 2 decimal places.
+///
+/// Each placeholder value is displayed as its own section, with the style of the section that
+/// contains it and the color chosen by its [`Placeholder`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::{color::palettes::css::LIME, prelude::*};
+/// use pyri_tooltip::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Heal {
+///     amount: f32,
+///     duration: f32,
+/// }
+///
+/// fn heal_amount(In(target): In<Entity>, heal_query: Query<&Heal>) -> PlaceholderValue {
+///     heal_query.get(target).map_or(0.0, |heal| heal.amount).into()
+/// }
+///
+/// fn heal_duration(In(target): In<Entity>, heal_query: Query<&Heal>) -> PlaceholderValue {
+///     heal_query.get(target).map_or(0.0, |heal| heal.duration).into()
+/// }
+///
+/// fn spawn_tooltip(world: &mut World) {
+///     let amount = world.register_system(heal_amount);
+///     let duration = world.register_system(heal_duration);
+///     world
+///         .resource_mut::<TooltipPlaceholders>()
+///         .insert("amount", Placeholder::new(amount).with_positive_color(LIME))
+///         .insert("duration", Placeholder::new(duration));
+///
+///     world.spawn((
+///         Heal { amount: 25.0, duration: 5.0 },
+///         Node::default(),
+///         Tooltip::cursor("Heals {amount} HP over {duration:.1}s"),
+///     ));
+/// }
+/// ```
+#[derive(Resource, Clone, Default, Debug)]
+pub struct TooltipPlaceholders {
+    /// The registered placeholders by name.
+    pub placeholders: HashMap<Cow<'static, str>, Placeholder>,
+}
+
+impl TooltipPlaceholders {
+    /// Register a [`Placeholder`] with the given name, replacing any previous placeholder with
+    /// the same name.
+    pub fn insert(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        placeholder: impl Into<Placeholder>,
+    ) -> &mut Self {
+        self.placeholders.insert(name.into(), placeholder.into());
+        self
+    }
+
+    /// Unregister the [`Placeholder`] with the given name.
+    pub fn remove(&mut self, name: &str) -> Option<Placeholder> {
+        self.placeholders.remove(name)
+    }
+}
+
+/// A placeholder resolver registered in [`TooltipPlaceholders`].
+///
+/// The resolver is a one-shot system that receives the tooltip's target entity as input. It's run
+/// at most once per frame while the tooltip is shown, no matter how many times the placeholder
+/// appears.
+#[derive(Clone, Copy, Debug)]
+pub struct Placeholder {
+    /// The one-shot system that computes the placeholder's value.
+    pub resolver: SystemId<In<Entity>, PlaceholderValue>,
+    /// The color of the value, or `None` to use the color of the surrounding text.
+    pub color: Option<Color>,
+    /// The color of positive numbers, overriding [`Self::color`].
+    pub positive_color: Option<Color>,
+    /// The color of negative numbers, overriding [`Self::color`].
+    pub negative_color: Option<Color>,
+}
+
+impl Placeholder {
+    /// Create a new `Placeholder` from a resolver system.
+    pub fn new(resolver: SystemId<In<Entity>, PlaceholderValue>) -> Self {
+        Self {
+            resolver,
+            color: None,
+            positive_color: None,
+            negative_color: None,
+        }
+    }
+
+    /// Set the color of the value.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the color of positive numbers.
+    pub fn with_positive_color(mut self, color: impl Into<Color>) -> Self {
+        self.positive_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of negative numbers.
+    pub fn with_negative_color(mut self, color: impl Into<Color>) -> Self {
+        self.negative_color = Some(color.into());
+        self
+    }
+
+    /// The color of the given value.
+    fn color_of(&self, value: &PlaceholderValue) -> Option<Color> {
+        match *value {
+            PlaceholderValue::Number(number) if number > 0.0 => self.positive_color,
+            PlaceholderValue::Number(number) if number < 0.0 => self.negative_color,
+            _ => None,
+        }
+        .or(self.color)
+    }
+}
+
+impl From<SystemId<In<Entity>, PlaceholderValue>> for Placeholder {
+    fn from(value: SystemId<In<Entity>, PlaceholderValue>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The value of a [`Placeholder`], returned by its resolver.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum PlaceholderValue {
+    /// A number that supports all formatting options.
+    Number(f64),
+    /// Text that's displayed as-is, ignoring formatting options.
+    Text(String),
+}
+
+impl PlaceholderValue {
+    /// Format the value with the given formatting options (e.g. `+.1`).
+    fn format(&self, options: &str) -> String {
+        let number = match *self {
+            Self::Number(number) => number,
+            Self::Text(ref text) => return text.clone(),
+        };
+
+        let mut sign = false;
+        let mut percent = false;
+        let mut decimals = None;
+        let mut chars = options.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '+' => sign = true,
+                '%' => percent = true,
+                '.' => {
+                    let mut end = i + 1;
+                    while let Some(&(j, c)) = chars.peek()
+                        && c.is_ascii_digit()
+                    {
+                        end = j + 1;
+                        chars.next();
+                    }
+                    decimals = options[i + 1..end].parse::<usize>().ok();
+                }
+                _ => {}
+            }
+        }
+
+        // Round percentages to hide floating point error (e.g. `7.000000000000001%`).
+        let (number, decimals) = if percent {
+            (number * 100.0, decimals.or(Some(PERCENT_DECIMALS)))
+        } else {
+            (number, decimals)
+        };
+        let trim = percent && !options.contains('.');
+        let mut text = match (sign, decimals) {
+            (false, None) => format!("{number}"),
+            (false, Some(decimals)) => format!("{number:.decimals$}"),
+            (true, None) => format!("{number:+}"),
+            (true, Some(decimals)) => format!("{number:+.decimals$}"),
+        };
+        if trim && text.contains('.') {
+            text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+        }
+        if percent {
+            text.push('%');
+        }
+        text
+    }
+}
+
+/// The maximum number of decimal places for percentages without the `.N` formatting option.
+const PERCENT_DECIMALS: usize = 2;
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for PlaceholderValue {
+                fn from(value: $ty) -> Self {
+                    Self::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(f64, i32, u32, i16, u16, i8, u8);
+
+impl From<f32> for PlaceholderValue {
+    fn from(value: f32) -> Self {
+        // Widen through the shortest decimal representation so `1.2` stays `1.2` instead of
+        // `1.2000000476837158`.
+        Self::Number(value.to_string().parse().unwrap_or(value.into()))
+    }
+}
+
+impl From<String> for PlaceholderValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for PlaceholderValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Replace the placeholders in the content of the current tooltip with their values.
+pub(crate) fn resolve_placeholders(world: &mut World) {
+    // Take the content out of the context so the resolvers can access the world.
+    let (target, mut content, mut companion, localized_args) = {
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        let content = core::mem::replace(
+            &mut ctx.tooltip.content,
+            TooltipContent::Custom(Entity::PLACEHOLDER),
+        );
+        let localized_args = core::mem::take(&mut ctx.localized_args);
+        (
+            ctx.target,
+            content,
+            ctx.tooltip.companion.take(),
+            localized_args,
+        )
+    };
+
+    // Look up only the registered placeholders that are used.
+    let mut used = Vec::<(Cow<'static, str>, Placeholder)>::new();
+    let registered = &world.resource::<TooltipPlaceholders>().placeholders;
+    if !registered.is_empty() {
+        let contents = core::iter::once(&mut content).chain(companion.as_mut());
+        for text in contents.flat_map(content_texts) {
+            for section in &text.sections {
+                if section.image.is_some() {
+                    continue;
+                }
+                split_placeholders(&section.value, |piece| {
+                    let Piece::Placeholder(placeholder) = piece else {
+                        return;
+                    };
+                    let name = placeholder
+                        .split_once(':')
+                        .map_or(placeholder, |(name, _)| name);
+                    if !used.iter().any(|(key, _)| key == name)
+                        && let Some((key, &placeholder)) = registered.get_key_value(name)
+                    {
+                        used.push((key.clone(), placeholder));
+                    }
+                });
+            }
+        }
+    }
+
+    // Run each resolver at most once.
+    let mut values = Vec::<(&str, Option<PlaceholderValue>)>::new();
+    let contents = core::iter::once(&mut content).chain(companion.as_mut());
+    for (content, args) in contents.zip(&localized_args) {
+        let mut resolve = |name: &str, options: &str| {
+            // Arguments of localized text take priority over registered placeholders.
+            if let Some((_, value)) = args.iter().find(|(arg_name, _)| arg_name == name) {
+                return Some((value.format(options), None));
+            }
+
+            let (key, placeholder) = used.iter().find(|(key, _)| key == name)?;
+            let key: &str = key;
+            let value = match values.iter().find(|&&(name, _)| name == key) {
+                Some((_, value)) => value.clone(),
+                None => {
+                    let value = world.run_system_with(placeholder.resolver, target).ok();
+                    values.push((key, value.clone()));
+                    value
+                }
+            }?;
+            Some((value.format(options), placeholder.color_of(&value)))
+        };
+
+        for text in content_texts(content) {
+            expand_placeholders(text, &mut resolve);
+        }
+    }
+
+    let mut ctx = world.resource_mut::<TooltipContext>();
+    ctx.tooltip.content = content;
+    ctx.tooltip.companion = companion;
+}
+
+/// The texts of primary and structured tooltip content that can contain placeholders.
+fn content_texts(content: &mut TooltipContent) -> impl Iterator<Item = &mut RichText> {
+    let (primary, structured) = match content {
+        TooltipContent::Primary(text) => (Some(text), None),
+        TooltipContent::Structured(structured) => (None, Some(structured)),
+        _ => (None, None),
+    };
+    let structured = structured.into_iter().flat_map(|structured| {
+        structured
+            .title
+            .iter_mut()
+            .chain(&mut structured.subtitle)
+            .chain(structured.stats.iter_mut().flat_map(|(k, v)| [k, v]))
+            .chain(&mut structured.description)
+            .chain(&mut structured.footer)
+    });
+    primary.into_iter().chain(structured)
+}
+
+/// A piece of text split around its placeholders.
+enum Piece<'a> {
+    /// Literal text, with `{{` and `}}` already unescaped.
+    Literal(&'a str),
+    /// The name and formatting options between the braces of a placeholder.
+    Placeholder(&'a str),
+}
+
+/// Split text into literal pieces and placeholders.
+fn split_placeholders<'a>(value: &'a str, mut f: impl FnMut(Piece<'a>)) {
+    let mut rest = value;
+    while let Some(i) = rest.find(['{', '}']) {
+        f(Piece::Literal(&rest[..i]));
+        let brace = &rest[i..i + 1];
+        rest = &rest[i + 1..];
+
+        // Unescape `{{` and `}}`, and keep unmatched braces.
+        if let Some(escaped) = rest.strip_prefix(brace) {
+            f(Piece::Literal(brace));
+            rest = escaped;
+            continue;
+        }
+        let end = match rest.find('}') {
+            Some(end) if brace == "{" => end,
+            _ => {
+                f(Piece::Literal(brace));
+                continue;
+            }
+        };
+
+        f(Piece::Placeholder(&rest[..end]));
+        rest = &rest[end + 1..];
+    }
+    f(Piece::Literal(rest));
+}
+
+/// Split the sections of a [`RichText`] around its placeholders, replacing each placeholder with
+/// its formatted value and color.
+fn expand_placeholders(
+    text: &mut RichText,
+    resolve: &mut impl FnMut(&str, &str) -> Option<(String, Option<Color>)>,
+) {
+    if !text
+        .sections
+        .iter()
+        .any(|section| section.image.is_none() && section.value.contains(['{', '}']))
+    {
+        return;
+    }
+
+    let mut sections = Vec::with_capacity(text.sections.len());
+    for section in core::mem::take(&mut text.sections) {
+        if section.image.is_some() || !section.value.contains(['{', '}']) {
+            sections.push(section);
+            continue;
+        }
+
+        let mut literal = String::new();
+        split_placeholders(&section.value, |piece| {
+            let placeholder = match piece {
+                Piece::Literal(text) => return literal.push_str(text),
+                Piece::Placeholder(placeholder) => placeholder,
+            };
+            let (name, options) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+            let Some((value, color)) = resolve(name, options) else {
+                literal.push('{');
+                literal.push_str(placeholder);
+                literal.push('}');
+                return;
+            };
+
+            if !literal.is_empty() {
+                let value = core::mem::take(&mut literal);
+                sections.push(TextSection::new(value, section.style.clone()));
+            }
+            let mut style = section.style.clone();
+            if let Some(color) = color {
+                style.color = color;
+            }
+            sections.push(TextSection::new(value, style));
+        });
+        if !literal.is_empty() {
+            sections.push(TextSection::new(literal, section.style));
+        }
+    }
+    text.sections = sections;
+}
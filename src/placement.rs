@@ -14,7 +14,7 @@ use bevy_ui::{
 use tiny_bail::prelude::*;
 
 use crate::{
-    TooltipContent, TooltipSettings, TooltipSystems,
+    TooltipDirection, TooltipSettings, TooltipSystems,
     context::{TooltipContext, TooltipState},
 };
 
@@ -46,6 +46,8 @@ pub struct TooltipPlacement {
     pub offset_y: Val,
     /// Clamp the tooltip entity within the window with additional padding.
     pub clamp_padding: UiRect,
+    /// The horizontal gap between the tooltip entity and its [companion](crate::Tooltip::companion).
+    pub companion_gap: Val,
 }
 
 impl TooltipPlacement {
@@ -56,6 +58,7 @@ impl TooltipPlacement {
         offset_x: Val::ZERO,
         offset_y: Val::ZERO,
         clamp_padding: UiRect::ZERO,
+        companion_gap: Val::Px(8.0),
     };
 
     /// Show the tooltip at the cursor.
@@ -65,6 +68,7 @@ impl TooltipPlacement {
         offset_x: Val::Px(16.0),
         offset_y: Val::Px(16.0),
         clamp_padding: UiRect::ZERO,
+        companion_gap: Val::Px(8.0),
     };
 
     /// Show the tooltip centered at the cursor as it moves.
@@ -74,6 +78,7 @@ impl TooltipPlacement {
         offset_x: Val::ZERO,
         offset_y: Val::ZERO,
         clamp_padding: UiRect::ZERO,
+        companion_gap: Val::Px(8.0),
     };
 
     /// Show the tooltip at the cursor as it moves.
@@ -83,7 +88,48 @@ impl TooltipPlacement {
         offset_x: Val::Px(16.0),
         offset_y: Val::Px(16.0),
         clamp_padding: UiRect::ZERO,
+        companion_gap: Val::Px(8.0),
     };
+
+    /// Replace a cursor target point with a fixed target point below the target entity.
+    pub(crate) fn without_cursor(self) -> Self {
+        if !matches!(self.target_point, TargetPoint::Cursor { .. }) {
+            return self;
+        }
+
+        Self {
+            anchor_point: Anchor::TOP_CENTER,
+            target_point: TargetPoint::Fixed(Anchor::BOTTOM_CENTER),
+            offset_x: Val::ZERO,
+            offset_y: Val::ZERO,
+            ..self
+        }
+    }
+}
+
+impl TooltipPlacement {
+    /// Mirror the placement horizontally for [`TooltipDirection::RightToLeft`].
+    pub(crate) fn with_direction(self, direction: TooltipDirection) -> Self {
+        if direction == TooltipDirection::LeftToRight {
+            return self;
+        }
+
+        let mirror = |anchor: Anchor| Anchor(anchor.0 * Vec2::new(-1.0, 1.0));
+        Self {
+            anchor_point: mirror(self.anchor_point),
+            target_point: match self.target_point {
+                TargetPoint::Fixed(anchor) => TargetPoint::Fixed(mirror(anchor)),
+                target_point => target_point,
+            },
+            offset_x: -self.offset_x,
+            clamp_padding: UiRect {
+                left: self.clamp_padding.right,
+                right: self.clamp_padding.left,
+                ..self.clamp_padding
+            },
+            ..self
+        }
+    }
 }
 
 impl From<Anchor> for TooltipPlacement {
@@ -94,6 +140,7 @@ impl From<Anchor> for TooltipPlacement {
             offset_x: Val::ZERO,
             offset_y: Val::ZERO,
             clamp_padding: UiRect::ZERO,
+            companion_gap: Val::Px(8.0),
         }
     }
 }
@@ -106,6 +153,7 @@ impl From<Vec2> for TooltipPlacement {
             offset_x: Val::Px(value.x),
             offset_y: Val::Px(value.y),
             clamp_padding: UiRect::ZERO,
+            companion_gap: Val::Px(8.0),
         }
     }
 }
@@ -126,17 +174,19 @@ fn place_tooltip(
     default_ui_camera: DefaultUiCamera,
     camera_query: Query<&Camera>,
     mut node_query: Query<&mut Node>,
-    gt_query: Query<&mut UiGlobalTransform>,
+    mut gt_query: Query<&mut UiGlobalTransform>,
     children_query: Query<&Children>,
 ) {
     rq!(matches!(ctx.state, TooltipState::Active));
     let target_gt = rq!(gt_query.get(ctx.target));
     let target_computed = rq!(computed_node_query.get(ctx.target));
-    let entity = match &ctx.tooltip.content {
-        TooltipContent::Primary(_) => primary.container,
-        &TooltipContent::Custom(id) => id,
-    };
+    let entity = ctx.content_entity(&primary);
+    // There's no content to place if lazy content failed to resolve.
+    rq!(entity != Entity::PLACEHOLDER);
     let computed = r!(computed_node_query.get(entity));
+    let companion = ctx
+        .companion_entity(&primary)
+        .and_then(|entity| Some((entity, computed_node_query.get(entity).ok()?)));
 
     // Identify the target camera and viewport rect.
     let camera_entity = r!(target_camera_query
@@ -150,8 +200,14 @@ fn place_tooltip(
     commands
         .entity(entity)
         .insert(UiTargetCamera(camera_entity));
+    if let Some((companion_entity, _)) = companion {
+        commands
+            .entity(companion_entity)
+            .insert(UiTargetCamera(camera_entity));
+    }
 
-    let placement = &ctx.tooltip.placement;
+    let direction = ctx.tooltip.direction.unwrap_or(primary.direction);
+    let placement = &ctx.tooltip.placement.with_direction(direction);
 
     // Calculate target position.
     let mut pos = if let TargetPoint::Fixed(target_anchor) = placement.target_point {
@@ -190,17 +246,50 @@ fn place_tooltip(
     let top = top.resolve(scale, size.x, size).unwrap_or_default();
     let bottom = bottom.resolve(scale, size.x, size).unwrap_or_default();
 
-    // Apply clamping.
+    // Calculate the extents of the tooltip (and its companion) around the tooltip's center.
     let half_size = computed.size / 2.0;
-    let mut left = half_size.x + left;
-    let mut right = size.x - half_size.x - right;
+    let mut extent_min = half_size;
+    let mut extent_max = half_size;
+    let mut companion_gap = 0.0;
+    let mut companion_on_right = true;
+    if let Some((_, companion_computed)) = companion {
+        companion_gap = placement
+            .companion_gap
+            .resolve(scale, size.x, size)
+            .unwrap_or_default()
+            .round();
+        let width = companion_gap + companion_computed.size.x;
+
+        // Place the companion on the side facing away from the target point, unless only the
+        // other side fits within the window.
+        companion_on_right = placement.anchor_point.0.x <= 0.0;
+        let fits_right = pos.x + half_size.x + width <= size.x - right;
+        let fits_left = pos.x - half_size.x - width >= left;
+        if companion_on_right && !fits_right && fits_left {
+            companion_on_right = false;
+        } else if !companion_on_right && !fits_left && fits_right {
+            companion_on_right = true;
+        }
+
+        if companion_on_right {
+            extent_max.x += width;
+        } else {
+            extent_min.x += width;
+        }
+        // The companion is top-aligned with the tooltip.
+        extent_max.y = extent_max.y.max(companion_computed.size.y - half_size.y);
+    }
+
+    // Apply clamping.
+    let mut left = extent_min.x + left;
+    let mut right = size.x - extent_max.x - right;
     if left > right {
         let mid = (left + right) / 2.0;
         left = mid;
         right = mid;
     }
-    let mut top = half_size.y + top;
-    let mut bottom = size.y - half_size.y - bottom;
+    let mut top = extent_min.y + top;
+    let mut bottom = size.y - extent_max.y - bottom;
     if top > bottom {
         let mid = (top + bottom) / 2.0;
         top = mid;
@@ -220,8 +309,44 @@ fn place_tooltip(
         pos.y = round_ties_up(pos.y + 0.5) - 0.5;
     }
 
-    // Set position via `Node`.
+    // Set the tooltip's position.
     let top_left = pos - half_size;
+    set_position(
+        entity,
+        top_left,
+        pos,
+        node_query.reborrow(),
+        gt_query.reborrow(),
+        children_query,
+    );
+
+    // Set the companion's position next to the tooltip.
+    let (companion_entity, companion_computed) = rq!(companion);
+    let companion_left = if companion_on_right {
+        top_left.x + computed.size.x + companion_gap
+    } else {
+        top_left.x - companion_gap - companion_computed.size.x
+    };
+    let companion_top_left = Vec2::new(companion_left.round(), top_left.y);
+    set_position(
+        companion_entity,
+        companion_top_left,
+        companion_top_left + companion_computed.size / 2.0,
+        node_query,
+        gt_query,
+        children_query,
+    );
+}
+
+fn set_position(
+    entity: Entity,
+    top_left: Vec2,
+    center: Vec2,
+    mut node_query: Query<&mut Node>,
+    gt_query: Query<&mut UiGlobalTransform>,
+    children_query: Query<&Children>,
+) {
+    // Set position via `Node`.
     let mut node = r!(node_query.get_mut(entity));
     node.left = Val::Px(top_left.x);
     node.top = Val::Px(top_left.y);
@@ -231,7 +356,7 @@ fn place_tooltip(
     // from the updated text. However, that means that `Node` positioning will be
     // delayed by 1 frame. As a workaround, update the `UiGlobalTransform` directly as well.
     let gt = r!(gt_query.get(entity));
-    let delta = Affine2::from_translation(pos - gt.translation);
+    let delta = Affine2::from_translation(center - gt.translation);
     update_gt_recursive(entity, delta, gt_query, children_query);
 }
 
@@ -1,28 +1,52 @@
 #![allow(missing_docs)]
 
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt;
 
 use bevy_app::{App, PostUpdate};
-use bevy_color::Color;
+use bevy_asset::Handle;
+use bevy_camera::visibility::{InheritedVisibility, Visibility, VisibilitySystems};
+use bevy_color::{Color, Srgba};
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::reflect::ReflectComponent;
 use bevy_ecs::{
+    bundle::Bundle,
+    change_detection::DetectChangesMut as _,
     component::Component,
     entity::Entity,
-    hierarchy::Children,
-    query::{Changed, With},
+    hierarchy::{ChildOf, Children},
+    name::Name,
+    query::{Changed, With, Without},
+    relationship::RelationshipTarget as _,
     schedule::{IntoScheduleConfigs as _, SystemSet},
-    system::{Commands, Query},
+    system::{Commands, EntityCommands, Query},
 };
+use bevy_image::{Image, TextureAtlas, TextureAtlasLayout};
 use bevy_text::{
-    FontSize, FontSmoothing, FontSource, Justify, LineBreak, LineHeight, TextColor, TextFont,
-    TextLayout, TextSpan,
+    ComputedTextBlock, FontSize, FontSmoothing, FontSource, FontStyle, FontWeight, Justify,
+    LineBreak, LineHeight, Strikethrough, StrikethroughColor, TextBackgroundColor, TextColor,
+    TextFont, TextLayout, TextLayoutInfo, TextSpan, Underline, UnderlineColor,
 };
-use bevy_ui::{UiSystems, widget::Text};
+use bevy_ui::{
+    ComputedNode, GlobalZIndex, Node, PositionType, UiGlobalTransform, UiSystems, Val, ZIndex,
+    widget::{ImageNode, Text},
+};
+use tiny_bail::prelude::*;
+
+use crate::TooltipSystems;
 
 pub(super) fn plugin(app: &mut App) {
     app.configure_sets(PostUpdate, RichTextSystems.before(UiSystems::Prepare));
-    app.add_systems(PostUpdate, sync_rich_text_spans.in_set(RichTextSystems));
+    app.add_systems(
+        PostUpdate,
+        (
+            sync_rich_text_spans.in_set(RichTextSystems),
+            place_inline_images
+                .after(UiSystems::PostLayout)
+                .after(TooltipSystems::Placement)
+                .before(VisibilitySystems::VisibilityPropagate),
+        ),
+    );
 }
 
 /// A [`SystemSet`] for the systems that update rich text entities in [`PostUpdate`].
@@ -33,6 +57,8 @@ fn sync_rich_text_spans(
     mut commands: Commands,
     rich_text_query: Query<(Entity, Option<&Children>, &RichText), Changed<RichText>>,
     text_span_query: Query<(), With<TextSpan>>,
+    inline_images_query: Query<&InlineImages>,
+    mut image_query: Query<&mut ImageNode, With<InlineImageOf>>,
 ) {
     for (entity, children, rich_text) in &rich_text_query {
         // Update root text entity.
@@ -57,46 +83,246 @@ fn sync_rich_text_spans(
 
             // Update text spans when there are still sections left to write.
             let section = &rich_text.sections[section_idx];
-            commands.entity(child).insert((
-                TextSpan(section.value.clone()),
-                TextColor(section.style.color),
-                TextFont {
-                    font: section.style.font.clone(),
-                    font_size: section.style.font_size,
-                    font_smoothing: rich_text.font_smoothing,
-                    ..Default::default()
-                },
-                rich_text.line_height,
-            ));
+            let mut span = commands.entity(child);
+            span.insert(text_span(rich_text, section));
+            insert_text_decorations(&mut span, section);
+
+            let image = inline_images_query
+                .get(child)
+                .ok()
+                .and_then(|images| images.collection().first().copied());
+            update_inline_image(&mut commands, &mut image_query, child, image, section);
             section_idx += 1;
         }
 
-        // If all sections are written, we're done.
-        if section_idx == rich_text.sections.len() {
-            continue;
+        // Spawn new text spans for the remaining sections.
+        for section in &rich_text.sections[section_idx..] {
+            let mut span = commands.spawn((text_span(rich_text, section), ChildOf(entity)));
+            insert_text_decorations(&mut span, section);
+            let child = span.id();
+            spawn_inline_image(&mut commands, child, section);
         }
+    }
+}
+
+/// The text that reserves space for an [`InlineImage`] in a text span: an em space.
+const INLINE_IMAGE_PLACEHOLDER: &str = "\u{2003}";
+
+fn text_span(rich_text: &RichText, section: &TextSection) -> impl Bundle {
+    let (value, color, font_size) = match &section.image {
+        // Scale the placeholder's em space to the size of the image.
+        Some(image) => (
+            INLINE_IMAGE_PLACEHOLDER.into(),
+            Color::NONE,
+            section.style.font_size * image.scale,
+        ),
+        None => (
+            section.value.clone(),
+            section.style.color,
+            section.style.font_size,
+        ),
+    };
+
+    (
+        TextSpan(value),
+        TextColor(color),
+        TextFont {
+            font: section.style.font.clone(),
+            font_size,
+            weight: section.style.font_weight,
+            style: section.style.font_style,
+            font_smoothing: rich_text.font_smoothing,
+            ..Default::default()
+        },
+        rich_text.line_height,
+    )
+}
+
+/// Replace the text decoration components of a text span.
+fn insert_text_decorations(span: &mut EntityCommands, section: &TextSection) {
+    span.remove::<(
+        Underline,
+        UnderlineColor,
+        Strikethrough,
+        StrikethroughColor,
+        TextBackgroundColor,
+    )>();
+    // Don't decorate the placeholder of an inline image.
+    rq!(section.image.is_none());
 
-        // Otherwise, spawn new text spans for the remaining sections.
-        commands.entity(entity).with_children(|parent| {
-            for section in &rich_text.sections[section_idx..] {
-                parent.spawn((
-                    TextSpan(section.value.clone()),
-                    TextColor(section.style.color),
-                    TextFont {
-                        font: section.style.font.clone(),
-                        font_size: section.style.font_size,
-                        font_smoothing: rich_text.font_smoothing,
-                        ..Default::default()
-                    },
-                    rich_text.line_height,
-                ));
+    let style = &section.style;
+    if style.underline {
+        span.insert(Underline);
+        if let Some(color) = style.decoration_color {
+            span.insert(UnderlineColor(color));
+        }
+    }
+    if style.strikethrough {
+        span.insert(Strikethrough);
+        if let Some(color) = style.decoration_color {
+            span.insert(StrikethroughColor(color));
+        }
+    }
+    if let Some(color) = style.highlight {
+        span.insert(TextBackgroundColor(color));
+    }
+}
+
+/// Update the existing inline image of a text span in place to avoid flickering, or spawn or
+/// despawn it if the section gained or lost its image.
+fn update_inline_image(
+    commands: &mut Commands,
+    image_query: &mut Query<&mut ImageNode, With<InlineImageOf>>,
+    span: Entity,
+    image: Option<Entity>,
+    section: &TextSection,
+) {
+    let Some(image) = image else {
+        spawn_inline_image(commands, span, section);
+        return;
+    };
+    let Some(inline_image) = &section.image else {
+        commands.entity(span).despawn_related::<InlineImages>();
+        return;
+    };
+
+    let mut image_node = r!(image_query.get_mut(image));
+    if (
+        &image_node.image,
+        &image_node.texture_atlas,
+        image_node.color,
+    ) != (
+        &inline_image.image,
+        &inline_image.texture_atlas,
+        inline_image.color,
+    ) {
+        image_node.image = inline_image.image.clone();
+        image_node.texture_atlas = inline_image.texture_atlas.clone();
+        image_node.color = inline_image.color;
+    }
+}
+
+fn spawn_inline_image(commands: &mut Commands, span: Entity, section: &TextSection) {
+    let image = rq!(section.image.as_ref());
+    // Use the expected size until the placeholder's size is known after text layout.
+    let size = match section.style.font_size * image.scale {
+        FontSize::Px(size) => Val::Px(size),
+        _ => Val::ZERO,
+    };
+
+    commands.spawn((
+        Name::new("InlineImage"),
+        InlineImageOf(span),
+        Node {
+            position_type: PositionType::Absolute,
+            width: size,
+            height: size,
+            ..Default::default()
+        },
+        ImageNode {
+            image: image.image.clone(),
+            texture_atlas: image.texture_atlas.clone(),
+            color: image.color,
+            ..Default::default()
+        },
+        Visibility::Hidden,
+    ));
+}
+
+/// The text span that an inline image entity belongs to.
+///
+/// Inline image entities are UI root nodes, because a text node can't have child nodes without
+/// losing its own text measurement.
+#[derive(Component)]
+#[relationship(relationship_target = InlineImages)]
+struct InlineImageOf(Entity);
+
+/// The inline image entities that belong to a text span.
+#[derive(Component)]
+#[relationship_target(relationship = InlineImageOf, linked_spawn)]
+struct InlineImages(Vec<Entity>);
+
+fn place_inline_images(
+    text_query: Query<
+        (
+            Entity,
+            &ComputedTextBlock,
+            &TextLayoutInfo,
+            &ComputedNode,
+            &UiGlobalTransform,
+            &InheritedVisibility,
+        ),
+        With<RichText>,
+    >,
+    inline_images_query: Query<&InlineImages>,
+    child_of_query: Query<&ChildOf>,
+    global_z_index_query: Query<&GlobalZIndex>,
+    mut image_query: Query<
+        (
+            &mut Node,
+            &mut UiGlobalTransform,
+            &mut Visibility,
+            Option<&GlobalZIndex>,
+        ),
+        (With<InlineImageOf>, Without<RichText>),
+    >,
+    mut commands: Commands,
+) {
+    for (entity, computed, layout, node, gt, inherited_visibility) in &text_query {
+        // Stack inline images above every other UI root node in the text's global z-index layer.
+        let global_z_index = core::iter::once(entity)
+            .chain(child_of_query.iter_ancestors(entity))
+            .find_map(|entity| global_z_index_query.get(entity).ok())
+            .copied()
+            .unwrap_or_default();
+
+        // Show or hide the inline images with the text.
+        let visibility = if inherited_visibility.get() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+
+        for run in &layout.run_geometry {
+            let span = cq!(computed.entities().get(run.section_index)).entity;
+            let images = cq!(inline_images_query.get(span));
+
+            // Center the image on the placeholder, scaled to the placeholder's width.
+            let size = run.bounds.width();
+            let center = gt.transform_point2(node.content_box().min + run.bounds.center());
+            let top_left = (center - size / 2.0) * node.inverse_scale_factor();
+            let size = Val::Px(size * node.inverse_scale_factor());
+
+            for &image in images.collection() {
+                let (mut image_node, mut image_gt, mut image_visibility, image_z_index) =
+                    c!(image_query.get_mut(image));
+
+                // Set position via `Node`, and via `UiGlobalTransform` to avoid a 1-frame delay.
+                let (left, top) = (Val::Px(top_left.x), Val::Px(top_left.y));
+                if (image_node.left, image_node.top, image_node.width) != (left, top, size) {
+                    image_node.left = left;
+                    image_node.top = top;
+                    image_node.width = size;
+                    image_node.height = size;
+                }
+                *image_gt = UiGlobalTransform::from_translation(center);
+
+                image_visibility.set_if_neq(visibility);
+                if image_z_index.is_none_or(|z| z.0 != global_z_index.0) {
+                    commands
+                        .entity(image)
+                        .insert((global_z_index, ZIndex(i32::MAX)));
+                }
             }
-        });
+        }
     }
 }
 
 /// A rich text string in the shape of Bevy 0.14's `Text` component.
-#[derive(Component, Clone, Default, Debug)]
+///
+/// Defaults to [`Justify::Start`], which follows the [`TooltipDirection`](crate::TooltipDirection)
+/// in tooltips.
+#[derive(Component, Clone, PartialEq, Debug)]
 #[require(Text)]
 #[cfg_attr(
     feature = "bevy_reflect",
@@ -111,6 +337,18 @@ pub struct RichText {
     pub font_smoothing: FontSmoothing,
 }
 
+impl Default for RichText {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            justify: Justify::Start,
+            linebreak_behavior: LineBreak::default(),
+            line_height: LineHeight::default(),
+            font_smoothing: FontSmoothing::default(),
+        }
+    }
+}
+
 impl RichText {
     pub fn from_section(value: impl Into<String>, style: TextStyle) -> Self {
         Self {
@@ -145,14 +383,531 @@ impl RichText {
         self.font_smoothing = font_smoothing;
         self
     }
+
+    /// Parse `RichText` from markup, starting from the default [`TextStyle`].
+    ///
+    /// See [`RichText::from_markup_with_style`] for the supported syntax.
+    pub fn from_markup(markup: &str) -> Result<Self, MarkupError> {
+        Self::from_markup_with_style(markup, TextStyle::default())
+    }
+
+    /// Parse `RichText` from markup, starting from the given [`TextStyle`].
+    ///
+    /// Supported tags, which can be nested:
+    ///
+    /// - `[b]...[/b]`: Bold.
+    /// - `[i]...[/i]`: Italic.
+    /// - `[weight=300]...[/weight]`: Font weight between 1 and 1000.
+    /// - `[color=#f00]...[/color]`: sRGB color in one of the hex forms `#rgb`, `#rgba`, `#rrggbb`,
+    ///   or `#rrggbbaa`, or the decimal forms `1,0,0` or `1,0,0,1`.
+    /// - `[size=24]...[/size]`: Font size in pixels.
+    /// - `[font=Fira Sans]...[/font]`: Font family name, or a generic family like `serif`,
+    ///   `sans-serif`, or `monospace`.
+    /// - `[u]...[/u]`: Underline.
+    /// - `[s]...[/s]`: Strikethrough.
+    /// - `[decoration=#00f]...[/decoration]`: Underline and strikethrough color, in the same forms
+    ///   as `color`.
+    /// - `[highlight=#ff0]...[/highlight]`: Background color, in the same forms as `color`.
+    ///
+    /// Use `[[` for a literal `[`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::text::{FontStyle, FontWeight};
+    /// use pyri_tooltip::prelude::*;
+    ///
+    /// let text = RichText::from_markup("[b]Damage[/b]: [color=#f00]12[/color]").unwrap();
+    /// assert_eq!(text.sections.len(), 3);
+    /// assert_eq!(RichText::from_markup(&text.to_markup()).unwrap().sections, text.sections);
+    ///
+    /// // Nested tags combine, and `[[` is a literal `[`.
+    /// let text = RichText::from_markup("[b]bold [i]both[/i][/b] [[plain]").unwrap();
+    /// let values: Vec<_> = text.sections.iter().map(|x| x.value.as_str()).collect();
+    /// assert_eq!(values, ["bold ", "both", " [plain]"]);
+    /// assert_eq!(text.sections[1].style.font_weight, FontWeight::BOLD);
+    /// assert_eq!(text.sections[1].style.font_style, FontStyle::Italic);
+    /// assert_eq!(text.sections[2].style, TextStyle::default());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MarkupError`] with the byte position of the first malformed tag.
+    pub fn from_markup_with_style(markup: &str, style: TextStyle) -> Result<Self, MarkupError> {
+        let mut sections = Vec::new();
+        let mut style = style;
+        let mut text = String::new();
+        // The open tags, with their positions and the styles to restore when they're closed.
+        let mut open_tags: Vec<(&str, usize, TextStyle)> = Vec::new();
+        let mut rest = markup;
+
+        while let Some(idx) = rest.find('[') {
+            text.push_str(&rest[..idx]);
+            let position = markup.len() - rest.len() + idx;
+            rest = &rest[idx + 1..];
+
+            // Unescape `[[` as a literal `[`.
+            if let Some(after) = rest.strip_prefix('[') {
+                text.push('[');
+                rest = after;
+                continue;
+            }
+
+            let end = rest
+                .find(']')
+                .ok_or(MarkupError::new(MarkupErrorKind::UnterminatedTag, position))?;
+            let tag = &rest[..end];
+            rest = &rest[end + 1..];
+
+            if !text.is_empty() {
+                sections.push(TextSection::new(core::mem::take(&mut text), style.clone()));
+            }
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let (open_name, _, prev_style) = open_tags.pop().ok_or_else(|| {
+                    MarkupError::new(MarkupErrorKind::UnexpectedClosingTag(name.into()), position)
+                })?;
+                if open_name != name {
+                    return Err(MarkupError::new(
+                        MarkupErrorKind::MismatchedClosingTag {
+                            expected: open_name.into(),
+                            found: name.into(),
+                        },
+                        position,
+                    ));
+                }
+                style = prev_style;
+            } else {
+                let (name, value) = match tag.split_once('=') {
+                    Some((name, value)) => (name, Some(value)),
+                    None => (tag, None),
+                };
+                let prev_style = style.clone();
+                apply_markup_tag(&mut style, name, value)
+                    .map_err(|kind| MarkupError::new(kind, position))?;
+                open_tags.push((name, position, prev_style));
+            }
+        }
+        text.push_str(rest);
+
+        if let Some(&(name, position, _)) = open_tags.last() {
+            return Err(MarkupError::new(
+                MarkupErrorKind::UnclosedTag(name.into()),
+                position,
+            ));
+        }
+        if !text.is_empty() {
+            sections.push(TextSection::new(text, style));
+        }
+
+        Ok(Self::from_sections(sections))
+    }
+
+    /// Convert to markup relative to the default [`TextStyle`].
+    ///
+    /// See [`RichText::to_markup_with_style`] for details.
+    pub fn to_markup(&self) -> String {
+        self.to_markup_with_style(&TextStyle::default())
+    }
+
+    /// Convert to markup relative to the given [`TextStyle`].
+    ///
+    /// Parsing the result with [`RichText::from_markup_with_style`] and the same style produces
+    /// the same sections, as long as every style is representable in markup. Colors that don't fit
+    /// in a hex color are written in decimal form, and adjacent sections with the same style are
+    /// separated by an empty `[b][/b]` tag.
+    ///
+    /// Font asset handles, non-pixel font sizes, oblique font styles, and non-sRGB colors are not
+    /// representable, so they're replaced by the given style or converted to sRGB. Markup can only
+    /// add italics, decorations, decoration colors, and highlights, so sections that are less
+    /// decorated than the given style (e.g. non-italic text when the given style is italic) are
+    /// not representable either. Empty sections and inline images are omitted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::{color::Color, text::FontWeight};
+    /// use pyri_tooltip::prelude::*;
+    ///
+    /// let sale = TextStyle {
+    ///     font_weight: FontWeight::BOLD,
+    ///     color: Color::srgb(1.0, 0.0, 0.0),
+    ///     ..Default::default()
+    /// };
+    /// let text = RichText::from_sections([
+    ///     TextSection::new("Price: ", TextStyle::default()),
+    ///     TextSection::new("[sale]", sale),
+    /// ]);
+    ///
+    /// let markup = text.to_markup();
+    /// assert_eq!(markup, "Price: [b][color=#FF0000][[sale][/color][/b]");
+    /// assert_eq!(RichText::from_markup(&markup).unwrap().sections, text.sections);
+    ///
+    /// // Colors keep their full precision, and adjacent sections stay separate.
+    /// let gray = TextStyle {
+    ///     color: Color::srgb(0.65, 0.65, 0.65),
+    ///     ..Default::default()
+    /// };
+    /// let text = RichText::from_sections([
+    ///     TextSection::new("a", TextStyle::default()),
+    ///     TextSection::new("b", TextStyle::default()),
+    ///     TextSection::new("c", gray),
+    /// ]);
+    ///
+    /// let markup = text.to_markup();
+    /// assert_eq!(markup, "a[b][/b]b[color=0.65,0.65,0.65,1]c[/color]");
+    /// assert_eq!(RichText::from_markup(&markup).unwrap().sections, text.sections);
+    /// ```
+    pub fn to_markup_with_style(&self, style: &TextStyle) -> String {
+        let mut markup = String::new();
+        // Whether the previous section was written without tags.
+        let mut prev_untagged = false;
+
+        for section in &self.sections {
+            if section.image.is_some() || section.value.is_empty() {
+                continue;
+            }
+
+            let mut close_tags = Vec::new();
+            let mut open = |markup: &mut String, tag: &str, name: &'static str| {
+                markup.push('[');
+                markup.push_str(tag);
+                markup.push(']');
+                close_tags.push(name);
+            };
+
+            if section.style.font != style.font
+                && let Some(font) = font_to_markup(&section.style.font)
+            {
+                open(&mut markup, &format!("font={font}"), "font");
+            }
+            if section.style.font_size != style.font_size
+                && let FontSize::Px(size) = section.style.font_size
+            {
+                open(&mut markup, &format!("size={size}"), "size");
+            }
+            if section.style.font_weight != style.font_weight {
+                if section.style.font_weight == FontWeight::BOLD {
+                    open(&mut markup, "b", "b");
+                } else {
+                    let weight = section.style.font_weight.0;
+                    open(&mut markup, &format!("weight={weight}"), "weight");
+                }
+            }
+            if section.style.font_style != style.font_style
+                && section.style.font_style == FontStyle::Italic
+            {
+                open(&mut markup, "i", "i");
+            }
+            if section.style.color != style.color {
+                let color = color_to_markup(section.style.color);
+                open(&mut markup, &format!("color={color}"), "color");
+            }
+            if section.style.underline && !style.underline {
+                open(&mut markup, "u", "u");
+            }
+            if section.style.strikethrough && !style.strikethrough {
+                open(&mut markup, "s", "s");
+            }
+            if section.style.decoration_color != style.decoration_color
+                && let Some(color) = section.style.decoration_color
+            {
+                let color = color_to_markup(color);
+                open(&mut markup, &format!("decoration={color}"), "decoration");
+            }
+            if section.style.highlight != style.highlight
+                && let Some(highlight) = section.style.highlight
+            {
+                let highlight = color_to_markup(highlight);
+                open(&mut markup, &format!("highlight={highlight}"), "highlight");
+            }
+
+            // Separate adjacent untagged sections so they aren't parsed as one.
+            let untagged = close_tags.is_empty();
+            if untagged && prev_untagged {
+                markup.push_str("[b][/b]");
+            }
+            prev_untagged = untagged;
+
+            markup.push_str(&section.value.replace('[', "[["));
+
+            for name in close_tags.into_iter().rev() {
+                markup.push_str("[/");
+                markup.push_str(name);
+                markup.push(']');
+            }
+        }
+
+        markup
+    }
 }
 
-/// A section of `RichText` in the shape of Bevy 0.14's `TextSection`.
-#[derive(Clone, Default, Debug)]
+fn apply_markup_tag(
+    style: &mut TextStyle,
+    name: &str,
+    value: Option<&str>,
+) -> Result<(), MarkupErrorKind> {
+    let invalid = || MarkupErrorKind::InvalidValue {
+        tag: name.into(),
+        value: value.unwrap_or_default().into(),
+    };
+
+    match (name, value) {
+        ("b", None) => style.font_weight = FontWeight::BOLD,
+        ("i", None) => style.font_style = FontStyle::Italic,
+        ("weight", Some(value)) => {
+            style.font_weight = value
+                .parse()
+                .ok()
+                .filter(|weight| (1..=1000).contains(weight))
+                .map(FontWeight)
+                .ok_or_else(invalid)?;
+        }
+        ("color", Some(value)) => {
+            style.color = color_from_markup(value).ok_or_else(invalid)?;
+        }
+        ("size", Some(value)) => {
+            style.font_size = value
+                .parse::<f32>()
+                .ok()
+                .filter(|size| size.is_finite() && *size > 0.0)
+                .map(FontSize::Px)
+                .ok_or_else(invalid)?;
+        }
+        ("font", Some(value)) if !value.is_empty() => style.font = font_from_markup(value),
+        ("font", Some(_)) => return Err(invalid()),
+        ("u", None) => style.underline = true,
+        ("s", None) => style.strikethrough = true,
+        ("decoration", Some(value)) => {
+            style.decoration_color = Some(color_from_markup(value).ok_or_else(invalid)?);
+        }
+        ("highlight", Some(value)) => {
+            style.highlight = Some(color_from_markup(value).ok_or_else(invalid)?);
+        }
+        ("b" | "i" | "u" | "s", Some(_)) => {
+            return Err(MarkupErrorKind::UnexpectedValue(name.into()));
+        }
+        ("weight" | "color" | "size" | "font" | "decoration" | "highlight", None) => {
+            return Err(MarkupErrorKind::MissingValue(name.into()));
+        }
+        _ => return Err(MarkupErrorKind::UnknownTag(name.into())),
+    }
+
+    Ok(())
+}
+
+fn color_from_markup(value: &str) -> Option<Color> {
+    if !value.contains(',') {
+        return Srgba::hex(value).ok().map(Into::into);
+    }
+
+    let mut channels = [1.0; 4];
+    let mut values = value.split(',');
+    for (i, channel) in channels.iter_mut().enumerate() {
+        match values.next() {
+            Some(value) => *channel = value.trim().parse().ok().filter(|x: &f32| x.is_finite())?,
+            // The alpha channel is optional.
+            None if i == 3 => break,
+            None => return None,
+        }
+    }
+    if values.next().is_some() {
+        return None;
+    }
+
+    let [red, green, blue, alpha] = channels;
+    Some(Color::srgba(red, green, blue, alpha))
+}
+
+fn color_to_markup(color: Color) -> String {
+    let color = Srgba::from(color);
+    let hex = color.to_hex();
+    if Srgba::hex(&hex).is_ok_and(|x| x == color) {
+        return hex;
+    }
+
+    let Srgba {
+        red,
+        green,
+        blue,
+        alpha,
+    } = color;
+    format!("{red},{green},{blue},{alpha}")
+}
+
+fn font_from_markup(value: &str) -> FontSource {
+    match value {
+        "serif" => FontSource::Serif,
+        "sans-serif" => FontSource::SansSerif,
+        "cursive" => FontSource::Cursive,
+        "fantasy" => FontSource::Fantasy,
+        "monospace" => FontSource::Monospace,
+        "system-ui" => FontSource::SystemUi,
+        "ui-serif" => FontSource::UiSerif,
+        "ui-sans-serif" => FontSource::UiSansSerif,
+        "ui-monospace" => FontSource::UiMonospace,
+        "ui-rounded" => FontSource::UiRounded,
+        "emoji" => FontSource::Emoji,
+        "math" => FontSource::Math,
+        "fangsong" => FontSource::FangSong,
+        family => FontSource::Family(family.into()),
+    }
+}
+
+fn font_to_markup(font: &FontSource) -> Option<&str> {
+    Some(match font {
+        FontSource::Handle(_) => return None,
+        FontSource::Family(family) => family.as_str(),
+        FontSource::Serif => "serif",
+        FontSource::SansSerif => "sans-serif",
+        FontSource::Cursive => "cursive",
+        FontSource::Fantasy => "fantasy",
+        FontSource::Monospace => "monospace",
+        FontSource::SystemUi => "system-ui",
+        FontSource::UiSerif => "ui-serif",
+        FontSource::UiSansSerif => "ui-sans-serif",
+        FontSource::UiMonospace => "ui-monospace",
+        FontSource::UiRounded => "ui-rounded",
+        FontSource::Emoji => "emoji",
+        FontSource::Math => "math",
+        FontSource::FangSong => "fangsong",
+    })
+}
+
+/// An error from parsing malformed `RichText` markup.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MarkupError {
+    /// What went wrong.
+    pub kind: MarkupErrorKind,
+    /// The byte offset of the offending tag in the markup.
+    pub position: usize,
+}
+
+impl MarkupError {
+    const fn new(kind: MarkupErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.position)
+    }
+}
+
+impl core::error::Error for MarkupError {}
+
+/// The kind of [`MarkupError`].
+///
+/// # Example
+///
+/// ```
+/// use pyri_tooltip::{MarkupErrorKind, RichText};
+///
+/// let error = |markup| RichText::from_markup(markup).unwrap_err();
+/// let kind = |markup| error(markup).kind;
+///
+/// assert_eq!(kind("ok [b"), MarkupErrorKind::UnterminatedTag);
+/// assert_eq!(kind("[x]a[/x]"), MarkupErrorKind::UnknownTag("x".into()));
+/// assert_eq!(kind("[color]a[/color]"), MarkupErrorKind::MissingValue("color".into()));
+/// assert_eq!(kind("[b=1]a[/b]"), MarkupErrorKind::UnexpectedValue("b".into()));
+/// assert_eq!(
+///     kind("[size=-1]a[/size]"),
+///     MarkupErrorKind::InvalidValue {
+///         tag: "size".into(),
+///         value: "-1".into(),
+///     },
+/// );
+/// assert_eq!(kind("a[/b]"), MarkupErrorKind::UnexpectedClosingTag("b".into()));
+/// assert_eq!(
+///     kind("[b][i]a[/b][/i]"),
+///     MarkupErrorKind::MismatchedClosingTag {
+///         expected: "i".into(),
+///         found: "b".into(),
+///     },
+/// );
+/// assert_eq!(kind("x [b]a"), MarkupErrorKind::UnclosedTag("b".into()));
+///
+/// // The position is the byte offset of the offending tag.
+/// assert_eq!(error("ok [b").position, 3);
+/// assert_eq!(error("a[/b]").position, 1);
+/// assert_eq!(error("[b][i]a[/b][/i]").position, 7);
+/// assert_eq!(error("x [b]a").to_string(), "unclosed tag `[b]` at byte 2");
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MarkupErrorKind {
+    /// A `[` without a matching `]`.
+    UnterminatedTag,
+    /// A tag that isn't supported.
+    UnknownTag(String),
+    /// A tag that requires a value, but doesn't have one.
+    MissingValue(String),
+    /// A tag that doesn't accept a value, but has one.
+    UnexpectedValue(String),
+    /// A tag with a value that couldn't be parsed.
+    InvalidValue { tag: String, value: String },
+    /// A closing tag without an open tag.
+    UnexpectedClosingTag(String),
+    /// A closing tag that doesn't match the innermost open tag.
+    MismatchedClosingTag { expected: String, found: String },
+    /// An open tag that's never closed.
+    UnclosedTag(String),
+}
+
+impl fmt::Display for MarkupErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedTag => write!(f, "unterminated tag"),
+            Self::UnknownTag(tag) => write!(f, "unknown tag `[{tag}]`"),
+            Self::MissingValue(tag) => write!(f, "missing value for tag `[{tag}]`"),
+            Self::UnexpectedValue(tag) => write!(f, "unexpected value for tag `[{tag}]`"),
+            Self::InvalidValue { tag, value } => {
+                write!(f, "invalid value `{value}` for tag `[{tag}]`")
+            }
+            Self::UnexpectedClosingTag(tag) => write!(f, "unexpected closing tag `[/{tag}]`"),
+            Self::MismatchedClosingTag { expected, found } => {
+                write!(
+                    f,
+                    "expected closing tag `[/{expected}]`, found `[/{found}]`"
+                )
+            }
+            Self::UnclosedTag(tag) => write!(f, "unclosed tag `[{tag}]`"),
+        }
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(value: &str) -> Self {
+        Self::from_section(value, TextStyle::default())
+    }
+}
+
+impl From<String> for RichText {
+    fn from(value: String) -> Self {
+        Self::from_section(value, TextStyle::default())
+    }
+}
+
+impl From<TextSection> for RichText {
+    fn from(value: TextSection) -> Self {
+        Self::from_sections([value])
+    }
+}
+
+impl From<Vec<TextSection>> for RichText {
+    fn from(value: Vec<TextSection>) -> Self {
+        Self::from_sections(value)
+    }
+}
+
+/// A section of `RichText` in the shape of Bevy 0.14's `TextSection`, plus an optional inline image.
+#[derive(Clone, Default, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TextSection {
     pub value: String,
     pub style: TextStyle,
+    /// An image to display inline in place of [`TextSection::value`].
+    pub image: Option<InlineImage>,
 }
 
 impl TextSection {
@@ -160,6 +915,7 @@ impl TextSection {
         Self {
             value: value.into(),
             style,
+            image: None,
         }
     }
 
@@ -167,6 +923,17 @@ impl TextSection {
         Self {
             value: String::new(),
             style,
+            image: None,
+        }
+    }
+
+    /// Create a new `TextSection` that displays an inline image sized relative to
+    /// [`TextStyle::font_size`].
+    pub fn image(image: impl Into<InlineImage>, style: TextStyle) -> Self {
+        Self {
+            value: String::new(),
+            style,
+            image: Some(image.into()),
         }
     }
 }
@@ -189,13 +956,86 @@ impl From<String> for TextSection {
     }
 }
 
-/// A text style in the shape of Bevy 0.14's `TextStyle`.
-#[derive(Clone, Debug)]
+/// An image to display inline with the text in a [`TextSection`].
+///
+/// The image is displayed in a square sized relative to the section's font size, in place of an
+/// em space in the text layout, so it wraps along with the surrounding text.
+///
+/// Inline images are drawn above every other UI root node in the text's [`GlobalZIndex`] layer,
+/// and are not clipped.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct InlineImage {
+    /// The image to display.
+    pub image: Handle<Image>,
+    /// The texture atlas to index into the image, if any.
+    pub texture_atlas: Option<TextureAtlas>,
+    /// The image size relative to the section's font size.
+    pub scale: f32,
+    /// The color to tint the image with.
+    pub color: Color,
+}
+
+impl InlineImage {
+    /// Create a new `InlineImage` from an image handle.
+    pub fn new(image: Handle<Image>) -> Self {
+        Self {
+            image,
+            texture_atlas: None,
+            scale: 1.0,
+            color: Color::WHITE,
+        }
+    }
+
+    /// Create a new `InlineImage` from an index into a texture atlas.
+    pub fn from_atlas(
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    ) -> Self {
+        Self {
+            texture_atlas: Some(TextureAtlas { layout, index }),
+            ..Self::new(image)
+        }
+    }
+
+    /// Set the image size relative to the section's font size.
+    pub const fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the color to tint the image with.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+impl From<Handle<Image>> for InlineImage {
+    fn from(image: Handle<Image>) -> Self {
+        Self::new(image)
+    }
+}
+
+/// A text style in the shape of Bevy 0.14's `TextStyle`, plus font weight, font style, and text
+/// decorations.
+#[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TextStyle {
     pub font: FontSource,
     pub font_size: FontSize,
+    pub font_weight: FontWeight,
+    pub font_style: FontStyle,
     pub color: Color,
+    /// Whether to draw a line under the text.
+    pub underline: bool,
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+    /// The color of the underline and strikethrough, or `None` to use [`Self::color`].
+    pub decoration_color: Option<Color>,
+    /// The background color behind the text, or `None` for no highlight.
+    pub highlight: Option<Color>,
 }
 
 impl Default for TextStyle {
@@ -203,7 +1043,13 @@ impl Default for TextStyle {
         Self {
             font: Default::default(),
             font_size: FontSize::Px(20.0),
+            font_weight: FontWeight::NORMAL,
+            font_style: FontStyle::Normal,
             color: Color::WHITE,
+            underline: false,
+            strikethrough: false,
+            decoration_color: None,
+            highlight: None,
         }
     }
 }
@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    children,
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    name::Name,
+    system::{Commands, EntityCommands},
+};
+use bevy_text::Justify;
+use bevy_ui::{BackgroundColor, FlexDirection, JustifyContent, Node};
+use tiny_bail::prelude::*;
+
+use crate::{
+    TooltipDirection,
+    rich_text::RichText,
+    theme::{StructuredTheme, restyle_default_fields},
+};
+
+/// Structured tooltip content with a common shape, displayed in the primary tooltip.
+///
+/// Each part is optional and laid out top to bottom, with dividers between the header (title and
+/// subtitle), the stats, the description, and the footer. Text styles are determined by
+/// [`TooltipTheme::structured`](crate::TooltipTheme::structured).
+///
+/// # Example
+///
+/// ```
+/// use pyri_tooltip::prelude::*;
+///
+/// let content = StructuredContent::new()
+///     .with_title("Iron Sword")
+///     .with_subtitle("Common")
+///     .with_stat("Damage", "12")
+///     .with_stat("Speed", "1.2")
+///     .with_description("A plain but reliable blade.")
+///     .with_footer("Right-click to equip");
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct StructuredContent {
+    /// The title line.
+    pub title: Option<RichText>,
+    /// The subtitle line below the title (e.g. rarity).
+    pub subtitle: Option<RichText>,
+    /// The key / value stat rows, displayed in two columns.
+    pub stats: Vec<(RichText, RichText)>,
+    /// The description.
+    pub description: Option<RichText>,
+    /// The hint footer.
+    pub footer: Option<RichText>,
+}
+
+impl StructuredContent {
+    /// Create a new empty `StructuredContent`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the title line.
+    pub fn with_title(mut self, title: impl Into<RichText>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the subtitle line.
+    pub fn with_subtitle(mut self, subtitle: impl Into<RichText>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Add a key / value stat row.
+    pub fn with_stat(mut self, key: impl Into<RichText>, value: impl Into<RichText>) -> Self {
+        self.stats.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: impl Into<RichText>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the hint footer.
+    pub fn with_footer(mut self, footer: impl Into<RichText>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Spawn the content as a child of the given primary tooltip container.
+    pub(crate) fn spawn(
+        self,
+        commands: &mut Commands,
+        container: Entity,
+        theme: &StructuredTheme,
+        direction: TooltipDirection,
+    ) {
+        let mut root = commands.spawn((
+            Name::new("StructuredContent"),
+            StructuredContentRoot,
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: theme.row_gap,
+                ..Default::default()
+            },
+            ChildOf(container),
+        ));
+
+        let mut needs_divider = false;
+        let divider = |root: &mut EntityCommands, needs_divider: &mut bool| {
+            if *needs_divider {
+                root.with_child((
+                    Name::new("Divider"),
+                    Node {
+                        height: theme.divider_thickness,
+                        ..Default::default()
+                    },
+                    BackgroundColor(theme.divider_color),
+                ));
+            }
+            *needs_divider = true;
+        };
+
+        // Header.
+        if self.title.is_some() || self.subtitle.is_some() {
+            divider(&mut root, &mut needs_divider);
+            for (name, text, style) in [
+                ("Title", self.title, &theme.title_style),
+                ("Subtitle", self.subtitle, &theme.subtitle_style),
+            ] {
+                let mut text = c!(text);
+                restyle_default_fields(&mut text, style);
+                text.justify = direction.resolve_justify(text.justify);
+                root.with_child((Name::new(name), text));
+            }
+        }
+
+        // Stats.
+        if !self.stats.is_empty() {
+            divider(&mut root, &mut needs_divider);
+            for (mut key, mut value) in self.stats {
+                restyle_default_fields(&mut key, &theme.stat_key_style);
+                restyle_default_fields(&mut value, &theme.stat_value_style);
+                key.justify = direction.resolve_justify(key.justify);
+                value.justify = direction.resolve_justify(Justify::End);
+                root.with_child((
+                    Name::new("Stat"),
+                    Node {
+                        flex_direction: match direction {
+                            TooltipDirection::LeftToRight => FlexDirection::Row,
+                            TooltipDirection::RightToLeft => FlexDirection::RowReverse,
+                        },
+                        justify_content: JustifyContent::SpaceBetween,
+                        column_gap: theme.column_gap,
+                        ..Default::default()
+                    },
+                    children![(Name::new("Key"), key), (Name::new("Value"), value)],
+                ));
+            }
+        }
+
+        // Description and footer.
+        for (name, text, style) in [
+            ("Description", self.description, &theme.description_style),
+            ("Footer", self.footer, &theme.footer_style),
+        ] {
+            let mut text = c!(text);
+            divider(&mut root, &mut needs_divider);
+            restyle_default_fields(&mut text, style);
+            text.justify = direction.resolve_justify(text.justify);
+            root.with_child((Name::new(name), text));
+        }
+    }
+}
+
+/// A marker component for the root entity of [`StructuredContent`] in a primary tooltip container.
+#[derive(Component)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub(crate) struct StructuredContentRoot;
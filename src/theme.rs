@@ -0,0 +1,296 @@
+use alloc::{borrow::Cow, vec};
+
+use bevy_app::{App, PreUpdate};
+use bevy_color::Color;
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{
+        IntoScheduleConfigs as _, SystemCondition as _,
+        common_conditions::{not, resource_added, resource_changed},
+    },
+    system::{Commands, Query, Res},
+};
+use bevy_platform::collections::HashMap;
+use bevy_text::{FontSize, FontStyle, FontWeight};
+use bevy_ui::{
+    BackgroundColor, BorderColor, BorderRadius, BoxShadow, Node, ShadowStyle, UiRect, Val,
+};
+use tiny_bail::prelude::*;
+
+use crate::{
+    TooltipSettings, TooltipSystems,
+    rich_text::{RichText, TextStyle},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        sync_tooltip_theme
+            .run_if(resource_changed::<TooltipTheme>.and_then(not(resource_added::<TooltipTheme>)))
+            .before(TooltipSystems::Content),
+    );
+}
+
+/// A [`Resource`] that defines the appearance of the primary tooltip.
+///
+/// Changes to this resource will be applied to [`TooltipSettings::container`] and
+/// [`TooltipSettings::companion_container`].
+///
+/// See [`TooltipPlugin::theme`](crate::TooltipPlugin::theme) to set the initial theme.
+///
+/// A [`Tooltip`](crate::Tooltip) can select one of the named [`variants`](Self::variants) with
+/// [`Tooltip::with_variant`](crate::Tooltip::with_variant).
+#[derive(Resource, Clone, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct TooltipTheme {
+    /// The background color.
+    pub background_color: Color,
+    /// The border thickness.
+    pub border: UiRect,
+    /// The border color.
+    pub border_color: Color,
+    /// The border radius.
+    pub border_radius: BorderRadius,
+    /// The padding around the content.
+    pub padding: UiRect,
+    /// The drop shadow, or `None` to disable the drop shadow.
+    pub shadow: Option<ShadowStyle>,
+    /// The maximum width.
+    pub max_width: Val,
+    /// The text style for primary tooltip text, e.g. from `TooltipContent::from("Hello, world!")`.
+    ///
+    /// Each field applies to the sections that use the default [`TextStyle`] value for that field,
+    /// so a bold section from markup still uses this style's font, size, and color.
+    pub text_style: TextStyle,
+    /// The layout and text styles for structured tooltip content.
+    pub structured: StructuredTheme,
+    /// Named style variants that override parts of the base theme when selected by a
+    /// [`Tooltip`](crate::Tooltip).
+    pub variants: HashMap<Cow<'static, str>, TooltipStyle>,
+}
+
+impl Default for TooltipTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Color::srgba(0.106, 0.118, 0.122, 0.9),
+            border: UiRect::ZERO,
+            border_color: Color::NONE,
+            border_radius: BorderRadius::ZERO,
+            padding: UiRect::all(Val::Px(8.0)),
+            shadow: None,
+            max_width: Val::Auto,
+            text_style: TextStyle::default(),
+            structured: StructuredTheme::default(),
+            variants: HashMap::default(),
+        }
+    }
+}
+
+impl TooltipTheme {
+    /// Register a named [`TooltipStyle`] variant.
+    pub fn with_variant(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        style: impl Into<TooltipStyle>,
+    ) -> Self {
+        self.variants.insert(name.into(), style.into());
+        self
+    }
+
+    /// The [`TooltipStyle`] variant with the given name, if it's registered.
+    pub fn variant(&self, name: Option<&str>) -> Option<&TooltipStyle> {
+        self.variants.get(name?)
+    }
+
+    /// The background color for the given variant.
+    pub(crate) fn background_color(&self, variant: Option<&TooltipStyle>) -> Color {
+        variant
+            .and_then(|variant| variant.background_color)
+            .unwrap_or(self.background_color)
+    }
+
+    /// The border color for the given variant.
+    pub(crate) fn border_color(&self, variant: Option<&TooltipStyle>) -> Color {
+        variant
+            .and_then(|variant| variant.border_color)
+            .unwrap_or(self.border_color)
+    }
+
+    /// Apply the theme's layout to a primary tooltip container [`Node`].
+    pub(crate) fn apply_to_node(&self, node: &mut Node) {
+        node.border = self.border;
+        node.border_radius = self.border_radius;
+        node.padding = self.padding;
+        node.max_width = self.max_width;
+    }
+
+    /// Apply the text style for the given variant to primary tooltip text, field by field.
+    pub(crate) fn apply_to_text(&self, variant: Option<&TooltipStyle>, text: &mut RichText) {
+        let style = variant
+            .and_then(|variant| variant.text_style.as_ref())
+            .unwrap_or(&self.text_style);
+        restyle_default_fields(text, style);
+    }
+
+    /// The [`BoxShadow`] component for the theme's drop shadow.
+    pub(crate) fn box_shadow(&self) -> BoxShadow {
+        BoxShadow(self.shadow.map(|shadow| vec![shadow]).unwrap_or_default())
+    }
+}
+
+/// Apply a text style to each section field by field, keeping the fields that a section changed
+/// from the default [`TextStyle`] (e.g. from markup or placeholders).
+pub(crate) fn restyle_default_fields(text: &mut RichText, style: &TextStyle) {
+    let default = TextStyle::default();
+    for section in &mut text.sections {
+        let old = &mut section.style;
+        if old.font == default.font {
+            old.font = style.font.clone();
+        }
+        if old.font_size == default.font_size {
+            old.font_size = style.font_size;
+        }
+        if old.font_weight == default.font_weight {
+            old.font_weight = style.font_weight;
+        }
+        if old.font_style == default.font_style {
+            old.font_style = style.font_style;
+        }
+        if old.color == default.color {
+            old.color = style.color;
+        }
+        old.underline |= style.underline;
+        old.strikethrough |= style.strikethrough;
+        old.decoration_color = old.decoration_color.or(style.decoration_color);
+        old.highlight = old.highlight.or(style.highlight);
+    }
+}
+
+/// The layout and text styles for [`StructuredContent`](crate::StructuredContent), part of
+/// [`TooltipTheme`].
+///
+/// Each text style applies to the sections of its part field by field, in the same way as
+/// [`TooltipTheme::text_style`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct StructuredTheme {
+    /// The text style for the title.
+    pub title_style: TextStyle,
+    /// The text style for the subtitle.
+    pub subtitle_style: TextStyle,
+    /// The text style for the stat keys in the left column.
+    pub stat_key_style: TextStyle,
+    /// The text style for the stat values in the right column.
+    pub stat_value_style: TextStyle,
+    /// The text style for the description.
+    pub description_style: TextStyle,
+    /// The text style for the footer.
+    pub footer_style: TextStyle,
+    /// The color of the dividers between parts.
+    pub divider_color: Color,
+    /// The thickness of the dividers between parts.
+    pub divider_thickness: Val,
+    /// The vertical gap between rows.
+    pub row_gap: Val,
+    /// The minimum horizontal gap between stat keys and values.
+    pub column_gap: Val,
+}
+
+impl Default for StructuredTheme {
+    fn default() -> Self {
+        let gray = Color::srgb(0.65, 0.65, 0.65);
+        Self {
+            title_style: TextStyle {
+                font_size: FontSize::Px(24.0),
+                font_weight: FontWeight::BOLD,
+                ..Default::default()
+            },
+            subtitle_style: TextStyle {
+                font_size: FontSize::Px(16.0),
+                color: gray,
+                ..Default::default()
+            },
+            stat_key_style: TextStyle {
+                font_size: FontSize::Px(16.0),
+                color: gray,
+                ..Default::default()
+            },
+            stat_value_style: TextStyle {
+                font_size: FontSize::Px(16.0),
+                ..Default::default()
+            },
+            description_style: TextStyle {
+                font_size: FontSize::Px(16.0),
+                ..Default::default()
+            },
+            footer_style: TextStyle {
+                font_size: FontSize::Px(14.0),
+                font_style: FontStyle::Italic,
+                color: gray,
+                ..Default::default()
+            },
+            divider_color: Color::srgba(1.0, 1.0, 1.0, 0.2),
+            divider_thickness: Val::Px(1.0),
+            row_gap: Val::Px(4.0),
+            column_gap: Val::Px(16.0),
+        }
+    }
+}
+
+/// A named style variant for the primary tooltip, registered in [`TooltipTheme::variants`].
+///
+/// Fields set to `None` fall back to the base [`TooltipTheme`].
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipStyle {
+    /// The background color.
+    pub background_color: Option<Color>,
+    /// The border color.
+    pub border_color: Option<Color>,
+    /// The text style for primary tooltip text, applied in the same way as
+    /// [`TooltipTheme::text_style`].
+    pub text_style: Option<TextStyle>,
+}
+
+impl TooltipStyle {
+    /// Set the background color.
+    pub fn with_background_color(mut self, color: impl Into<Color>) -> Self {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    /// Set the border color.
+    pub fn with_border_color(mut self, color: impl Into<Color>) -> Self {
+        self.border_color = Some(color.into());
+        self
+    }
+
+    /// Set the text style.
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = Some(text_style);
+        self
+    }
+}
+
+fn sync_tooltip_theme(
+    mut commands: Commands,
+    theme: Res<TooltipTheme>,
+    settings: Res<TooltipSettings>,
+    mut node_query: Query<&mut Node>,
+) {
+    for container in [settings.container, settings.companion_container] {
+        let mut node = c!(node_query.get_mut(container));
+        theme.apply_to_node(&mut node);
+        commands.entity(container).insert((
+            BackgroundColor(theme.background_color),
+            BorderColor::all(theme.border_color),
+            theme.box_shadow(),
+        ));
+    }
+}